@@ -0,0 +1,177 @@
+//! Conversion benchmarks. Run with `cargo bench`; `cargo bench -- <filter>`
+//! runs a subset, such as `cargo bench -- from/` for the `From` impls.
+//!
+//! Inputs are generated from a fixed seed, so every run converts the same
+//! colors.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use hsluv::*;
+
+const COLORS: usize = 1024;
+const WIDTH: usize = 3840;
+const HEIGHT: usize = 2160;
+
+// xorshift64, for colors spread evenly over the gamut.
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn rgb(&mut self) -> Rgb {
+        Rgb {
+            red: self.next(),
+            green: self.next(),
+            blue: self.next(),
+        }
+    }
+}
+
+fn random_rgb(len: usize) -> Vec<Rgb> {
+    let mut random = Random(0x9e37_79b9_7f4a_7c15);
+    (0..len).map(|_| random.rgb()).collect()
+}
+
+fn bench_from<T, U>(c: &mut Criterion, name: &str, inputs: &[T])
+where
+    T: Copy,
+    U: From<T>,
+{
+    let mut group = c.benchmark_group("from");
+    group.throughput(Throughput::Elements(inputs.len() as u64));
+    group.bench_function(name, |b| {
+        b.iter(|| {
+            for input in inputs {
+                black_box(U::from(black_box(*input)));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn from_impls(c: &mut Criterion) {
+    let rgb = random_rgb(COLORS);
+    let xyz = rgb.iter().map(|&rgb| Xyz::from(rgb)).collect::<Vec<_>>();
+    let luv = xyz.iter().map(|&xyz| Luv::from(xyz)).collect::<Vec<_>>();
+    let lch = luv.iter().map(|&luv| Lch::from(luv)).collect::<Vec<_>>();
+    let hsluv = lch.iter().map(|&lch| Hsluv::from(lch)).collect::<Vec<_>>();
+    let hpluv = lch.iter().map(|&lch| Hpluv::from(lch)).collect::<Vec<_>>();
+
+    bench_from::<Rgb, Xyz>(c, "rgb_to_xyz", &rgb);
+    bench_from::<Xyz, Rgb>(c, "xyz_to_rgb", &xyz);
+    bench_from::<Xyz, Luv>(c, "xyz_to_luv", &xyz);
+    bench_from::<Luv, Xyz>(c, "luv_to_xyz", &luv);
+    bench_from::<Luv, Lch>(c, "luv_to_lch", &luv);
+    bench_from::<Lch, Luv>(c, "lch_to_luv", &lch);
+    bench_from::<Lch, Hsluv>(c, "lch_to_hsluv", &lch);
+    bench_from::<Hsluv, Lch>(c, "hsluv_to_lch", &hsluv);
+    bench_from::<Lch, Hpluv>(c, "lch_to_hpluv", &lch);
+    bench_from::<Hpluv, Lch>(c, "hpluv_to_lch", &hpluv);
+    bench_from::<Rgb, Hsluv>(c, "rgb_to_hsluv", &rgb);
+    bench_from::<Hsluv, Rgb>(c, "hsluv_to_rgb", &hsluv);
+    bench_from::<Rgb, Hpluv>(c, "rgb_to_hpluv", &rgb);
+    bench_from::<Hpluv, Rgb>(c, "hpluv_to_rgb", &hpluv);
+}
+
+fn bench_tuple_fn(
+    c: &mut Criterion,
+    name: &str,
+    inputs: &[(f64, f64, f64)],
+    f: fn(f64, f64, f64) -> (f64, f64, f64),
+) {
+    let mut group = c.benchmark_group("fn");
+    group.throughput(Throughput::Elements(inputs.len() as u64));
+    group.bench_function(name, |b| {
+        b.iter(|| {
+            for &(x, y, z) in inputs {
+                black_box(f(black_box(x), black_box(y), black_box(z)));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn top_level_functions(c: &mut Criterion) {
+    let rgb = random_rgb(COLORS);
+    let hsluv = rgb
+        .iter()
+        .map(|&rgb| Hsluv::from(rgb).hsl())
+        .collect::<Vec<_>>();
+    let hpluv = rgb
+        .iter()
+        .map(|&rgb| Hpluv::from(rgb).hsl())
+        .collect::<Vec<_>>();
+    let rgb = rgb.iter().map(Rgb::rgb).collect::<Vec<_>>();
+
+    bench_tuple_fn(c, "hsluv_to_rgb", &hsluv, hsluv_to_rgb);
+    bench_tuple_fn(c, "hpluv_to_rgb", &hpluv, hpluv_to_rgb);
+    bench_tuple_fn(c, "rgb_to_hsluv", &rgb, rgb_to_hsluv);
+    bench_tuple_fn(c, "rgb_to_hpluv", &rgb, rgb_to_hpluv);
+}
+
+fn bounds(c: &mut Criterion) {
+    let lch = random_rgb(COLORS)
+        .into_iter()
+        .map(|rgb| Lch::from(Luv::from(Xyz::from(rgb))))
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("bounds");
+    group.throughput(Throughput::Elements(lch.len() as u64));
+    group.bench_function("max_chroma_for_lightness_hue", |b| {
+        b.iter(|| {
+            for lch in &lch {
+                black_box(max_chroma_for_lightness_hue(
+                    black_box(lch.lightness),
+                    black_box(lch.hue),
+                ));
+            }
+        })
+    });
+    group.bench_function("max_safe_chroma_for_lightness", |b| {
+        b.iter(|| {
+            for lch in &lch {
+                black_box(max_safe_chroma_for_lightness(black_box(lch.lightness)));
+            }
+        })
+    });
+    group.finish();
+}
+
+// A 4K frame, converted whole per iteration. `cargo test` runs each benchmark
+// once to smoke-test it, which only needs a thumbnail.
+fn batch(c: &mut Criterion) {
+    let benchmarking = std::env::args().any(|arg| arg == "--bench");
+    let scale = if benchmarking { 1 } else { 60 };
+    let rgb = random_rgb(WIDTH / scale * (HEIGHT / scale));
+    let rgb8 = rgb.iter().map(Rgb::to_rgb8).collect::<Vec<_>>();
+    let mut hsluv = rgb.iter().map(|&rgb| Hsluv::from(rgb)).collect::<Vec<_>>();
+    let mut hpluv = rgb.iter().map(|&rgb| Hpluv::from(rgb)).collect::<Vec<_>>();
+    let mut out = rgb.clone();
+
+    let mut group = c.benchmark_group("batch_4k");
+    group.throughput(Throughput::Elements(rgb.len() as u64));
+    group.sample_size(10);
+    group.bench_function("hsluv_to_rgb_slice", |b| {
+        b.iter(|| hsluv_to_rgb_slice(black_box(&hsluv), &mut out))
+    });
+    group.bench_function("hpluv_to_rgb_slice", |b| {
+        b.iter(|| hpluv_to_rgb_slice(black_box(&hpluv), &mut out))
+    });
+    group.bench_function("rgb_to_hsluv_slice", |b| {
+        b.iter(|| rgb_to_hsluv_slice(black_box(&rgb), &mut hsluv))
+    });
+    group.bench_function("rgb_to_hpluv_slice", |b| {
+        b.iter(|| rgb_to_hpluv_slice(black_box(&rgb), &mut hpluv))
+    });
+    group.bench_function("rgb8_to_hsluv_slice", |b| {
+        b.iter(|| rgb8_to_hsluv_slice(black_box(&rgb8), &mut hsluv))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, from_impls, top_level_functions, bounds, batch);
+criterion_main!(benches);
@@ -1,70 +1,161 @@
 use std::{
+    collections::BTreeMap,
     error::Error,
     fs::{self, File},
     io::Read,
     path::Path,
 };
 
-fn main() -> Result<(), Box<dyn Error>> {
-    println!("cargo:rerun-if-changed=snapshot-rev4.json");
+// Every `.json` file here becomes a test module named after it, so new
+// revisions and other implementations' reference outputs are tested by
+// dropping them in.
+const SNAPSHOTS: &str = "snapshots";
 
-    let out_dir = std::env::var_os("OUT_DIR").expect("No OUT_DIR provided");
-    let dest_path = Path::new(&out_dir).join("snapshot-rev4.rs");
+// Each color space in a snapshot entry, with its component names in order.
+const SPACES: [(&str, &str, [&str; 3]); 6] = [
+    ("lch", "Lch", ["lightness", "chroma", "hue"]),
+    ("luv", "Luv", ["lightness", "u", "v"]),
+    ("rgb", "Rgb", ["red", "green", "blue"]),
+    ("xyz", "Xyz", ["x", "y", "z"]),
+    ("hpluv", "Hpluv", ["hue", "saturation", "lightness"]),
+    ("hsluv", "Hsluv", ["hue", "saturation", "lightness"]),
+];
+
+fn is_hex_color(color: &str) -> bool {
+    let bytes = color.as_bytes();
+    bytes.len() == 7
+        && bytes[0] == b'#'
+        && bytes[1..]
+            .iter()
+            .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(b))
+}
+
+// Checks a snapshot entry against the schema and renders it as a
+// `ColorValues` literal.
+fn color_values(color: &str, values: &json::JsonValue) -> Result<String, String> {
+    if !is_hex_color(color) {
+        return Err(format!(
+            "{:?}: key isn't a lowercase \"#rrggbb\" color",
+            color
+        ));
+    }
+    if !values.is_object() {
+        return Err(format!("{:?}: expected an object, got {}", color, values));
+    }
+    if let Some((key, _)) = values
+        .entries()
+        .find(|(key, _)| !SPACES.iter().any(|(space, _, _)| space == key))
+    {
+        return Err(format!("{:?}: unexpected field {:?}", color, key));
+    }
+
+    let mut out = String::from("ColorValues { ");
+    for (space, ty, components) in SPACES.iter() {
+        let array = &values[*space];
+        if array.is_null() {
+            return Err(format!("{:?}: missing field {:?}", color, space));
+        }
+        if !array.is_array() || array.len() != components.len() {
+            return Err(format!(
+                "{:?}.{}: expected an array of {} numbers ({}), got {}",
+                color,
+                space,
+                components.len(),
+                components.join(", "),
+                array
+            ));
+        }
+        out.push_str(&format!("{}: {} {{ ", space, ty));
+        for (i, component) in components.iter().enumerate() {
+            let value = array[i].as_f64().ok_or_else(|| {
+                format!(
+                    "{:?}.{}[{}] ({}): expected a number, got {}",
+                    color, space, i, component, array[i]
+                )
+            })?;
+            out.push_str(&format!("{}: {:?}, ", component, value));
+        }
+        out.push_str("}, ");
+    }
+    out.push('}');
+    Ok(out)
+}
+
+// Always starts with `snapshot_`, so stems starting with a digit still make an
+// identifier: `snapshot-rev4` becomes `snapshot_rev4`, and `4k` becomes
+// `snapshot_4k`.
+fn module_name(stem: &str) -> String {
+    let stem = stem.strip_prefix("snapshot-").unwrap_or(stem);
+    let sanitized = stem.chars().map(|c| {
+        if c.is_ascii_alphanumeric() {
+            c.to_ascii_lowercase()
+        } else {
+            '_'
+        }
+    });
+    "snapshot_".chars().chain(sanitized).collect()
+}
 
+// Renders one snapshot file as a test module holding its colors.
+fn snapshot_module(path: &Path, module: &str) -> Result<String, String> {
+    let name = path.display();
     let mut s = String::new();
-    File::open("snapshot-rev4.json")
-        .expect("Snapshot file not present")
-        .read_to_string(&mut s)
-        .expect("Can't record snapshot file");
-    let json = json::parse(&s).expect("Can't parse snapshot");
+    File::open(path)
+        .and_then(|mut file| file.read_to_string(&mut s))
+        .map_err(|error| format!("{}: can't read snapshot: {}", name, error))?;
+    let json =
+        json::parse(&s).map_err(|error| format!("{}: can't parse snapshot: {}", name, error))?;
     if !json.is_object() {
-        panic!("Snapshot isn't an object");
+        return Err(format!("{}: snapshot isn't an object", name));
     }
 
-    let mut out = String::new();
-
-    out.push_str("static SNAPSHOT: &[(&str, ColorValues)] = &[\n");
+    let mut out = format!("mod {} {{\n    use super::*;\n\n", module);
+    out.push_str("    pub(super) static SNAPSHOT: &[(&str, ColorValues)] = &[\n");
     for (color, values) in json.entries() {
-        let lch = &values["lch"];
-        let luv = &values["luv"];
-        let rgb = &values["rgb"];
-        let xyz = &values["xyz"];
-        let hpluv = &values["hpluv"];
-        let hsluv = &values["hsluv"];
+        let color_values =
+            color_values(color, values).map_err(|error| format!("{}: {}", name, error))?;
+        out.push_str(&format!("        ({:?}, {}),\n", color, color_values));
+    }
+    out.push_str("    ];\n\n");
+    for test in ["top_level_functions", "hex"].iter() {
+        out.push_str(&format!(
+            "    #[test]\n    fn {}() {{\n        check_{}(SNAPSHOT);\n    }}\n",
+            test, test
+        ));
+    }
+    out.push_str("}\n");
+    Ok(out)
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    println!("cargo:rerun-if-changed={}", SNAPSHOTS);
+
+    let out_dir = std::env::var_os("OUT_DIR").expect("No OUT_DIR provided");
+    let dest_path = Path::new(&out_dir).join("snapshots.rs");
 
-        let color_values = format!(
-            concat!(
-                "ColorValues {{ ",
-                "lch: Lch {{ lightness: {:?}, chroma: {:?}, hue: {:?} }}, ",
-                "luv: Luv {{ lightness: {:?}, u: {:?}, v: {:?} }}, ",
-                "rgb: Rgb {{ red: {:?}, green: {:?}, blue: {:?} }}, ",
-                "xyz: Xyz {{ x: {:?}, y: {:?}, z: {:?} }}, ",
-                "hpluv: Hpluv {{ hue: {:?}, saturation: {:?}, lightness: {:?} }}, ",
-                "hsluv: Hsluv {{ hue: {:?}, saturation: {:?}, lightness: {:?} }}, ",
-                "}}",
-            ),
-            lch[0].as_f64().expect("LCH missing lightness"),
-            lch[1].as_f64().expect("LCH missing chroma"),
-            lch[2].as_f64().expect("LCH missing hue"),
-            luv[0].as_f64().expect("LUV missing lightness"),
-            luv[1].as_f64().expect("LUV missing u"),
-            luv[2].as_f64().expect("LUV missing v"),
-            rgb[0].as_f64().expect("RGB missing red"),
-            rgb[1].as_f64().expect("RGB missing green"),
-            rgb[2].as_f64().expect("RGB missing blue"),
-            xyz[0].as_f64().expect("XYZ missing X"),
-            xyz[1].as_f64().expect("XYZ missing Y"),
-            xyz[2].as_f64().expect("XYZ missing Z"),
-            hpluv[0].as_f64().expect("HPLuv missing hue"),
-            hpluv[1].as_f64().expect("HPLuv missing saturation"),
-            hpluv[2].as_f64().expect("HPLuv missing lightness"),
-            hsluv[0].as_f64().expect("HSLuv missing lightness"),
-            hsluv[1].as_f64().expect("HSLuv missing lightness"),
-            hsluv[2].as_f64().expect("HSLuv missing lightness"),
-        );
-        out.push_str(&format!("    ({:?}, {}),\n", color, color_values));
+    let mut paths = fs::read_dir(SNAPSHOTS)
+        .expect("Snapshot directory not present")
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    paths.retain(|path| path.extension() == Some("json".as_ref()));
+    paths.sort();
+
+    let mut modules = BTreeMap::new();
+    for path in &paths {
+        let stem = path.file_stem().and_then(|stem| stem.to_str()).unwrap();
+        if let Some(other) = modules.insert(module_name(stem), path) {
+            panic!(
+                "{} and {} would both become test module `{}`; rename one",
+                other.display(),
+                path.display(),
+                module_name(stem)
+            );
+        }
+    }
+    let mut out = String::new();
+    for (module, path) in &modules {
+        out.push_str(&snapshot_module(path, module)?);
     }
-    out.push_str("];\n");
 
     fs::write(&dest_path, out)?;
     println!("cargo:rerun-if-changed=build.rs");
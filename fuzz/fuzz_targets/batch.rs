@@ -0,0 +1,83 @@
+//! The slice conversions on arbitrary colors. Nothing may panic, and each
+//! result must match the per-color `From` impl bit for bit.
+
+#![no_main]
+
+use hsluv::*;
+use libfuzzer_sys::fuzz_target;
+
+fn check_same(from: &dyn core::fmt::Debug, expected: (f64, f64, f64), actual: (f64, f64, f64)) {
+    let same = |a: f64, b: f64| a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan());
+    assert!(
+        same(expected.0, actual.0) && same(expected.1, actual.1) && same(expected.2, actual.2),
+        "{:?} -> {:?}, expected {:?}",
+        from,
+        actual,
+        expected
+    );
+}
+
+fuzz_target!(|input: Vec<(f64, f64, f64)>| {
+    let hsluv: Vec<_> = input
+        .iter()
+        .map(|&(hue, saturation, lightness)| Hsluv {
+            hue,
+            saturation,
+            lightness,
+        })
+        .collect();
+    let hpluv: Vec<_> = input
+        .iter()
+        .map(|&(hue, saturation, lightness)| Hpluv {
+            hue,
+            saturation,
+            lightness,
+        })
+        .collect();
+    let rgb: Vec<_> = input
+        .iter()
+        .map(|&(red, green, blue)| Rgb { red, green, blue })
+        .collect();
+    let rgb8: Vec<_> = input
+        .iter()
+        .map(|&(red, green, blue)| {
+            [
+                red.to_bits() as u8,
+                green.to_bits() as u8,
+                blue.to_bits() as u8,
+            ]
+        })
+        .collect();
+
+    let black = Rgb::from_rgb8([0; 3]);
+    let mut rgb_out = vec![black; input.len()];
+    let mut hsluv_out = vec![Hsluv::from(black); input.len()];
+    let mut hpluv_out = vec![Hpluv::from(black); input.len()];
+
+    hsluv_to_rgb_slice(&hsluv, &mut rgb_out);
+    for (hsluv, rgb) in hsluv.iter().zip(&rgb_out) {
+        check_same(hsluv, Rgb::from(*hsluv).rgb(), rgb.rgb());
+    }
+    hpluv_to_rgb_slice(&hpluv, &mut rgb_out);
+    for (hpluv, rgb) in hpluv.iter().zip(&rgb_out) {
+        check_same(hpluv, Rgb::from(*hpluv).rgb(), rgb.rgb());
+    }
+    rgb_to_hsluv_slice(&rgb, &mut hsluv_out);
+    for (rgb, hsluv) in rgb.iter().zip(&hsluv_out) {
+        check_same(rgb, Hsluv::from(*rgb).hsl(), hsluv.hsl());
+    }
+    rgb_to_hpluv_slice(&rgb, &mut hpluv_out);
+    for (rgb, hpluv) in rgb.iter().zip(&hpluv_out) {
+        check_same(rgb, Hpluv::from(*rgb).hsl(), hpluv.hsl());
+    }
+    rgb8_to_hsluv_slice(&rgb8, &mut hsluv_out);
+    for (rgb8, hsluv) in rgb8.iter().zip(&hsluv_out) {
+        let expected = Hsluv::from(Rgb::from_rgb8(*rgb8));
+        check_same(rgb8, expected.hsl(), hsluv.hsl());
+    }
+    rgb8_to_hpluv_slice(&rgb8, &mut hpluv_out);
+    for (rgb8, hpluv) in rgb8.iter().zip(&hpluv_out) {
+        let expected = Hpluv::from(Rgb::from_rgb8(*rgb8));
+        check_same(rgb8, expected.hsl(), hpluv.hsl());
+    }
+});
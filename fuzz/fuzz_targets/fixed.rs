@@ -0,0 +1,27 @@
+//! The integer-only conversion over its whole input space. It may not panic
+//! or overflow, and must stay within one 8-bit step of the `f64` path.
+
+#![no_main]
+
+use hsluv::*;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (u16, u16, u16)| {
+    let (hue, saturation, lightness) = input;
+    let fixed = hsluv_to_rgb8_fixed(hue, saturation, lightness);
+    let float = Rgb::from(Hsluv {
+        hue: f64::from(hue) / 65536.0 * 360.0,
+        saturation: f64::from(saturation) / 65535.0 * 100.0,
+        lightness: f64::from(lightness) / 65535.0 * 100.0,
+    })
+    .to_rgb8();
+    for (fixed, float) in fixed.iter().zip(float.iter()) {
+        assert!(
+            (i16::from(*fixed) - i16::from(*float)).abs() <= 1,
+            "{:?} -> {:?}, expected {:?}",
+            input,
+            fixed,
+            float
+        );
+    }
+});
@@ -0,0 +1,40 @@
+//! The gamut queries on arbitrary components. Nothing may panic, finite hues
+//! must give finite cusps and ordered lightness ranges within 0..=100, and
+//! anything non-finite must give a NaN cusp or no range.
+
+#![no_main]
+
+use hsluv::*;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (f64, f64, f64)| {
+    let (hue, chroma, lightness) = input;
+    let _ = max_safe_chroma_for_lightness(lightness);
+    let _ = max_chroma_for_lightness_hue(lightness, hue);
+    let cusp = cusp_for_hue(hue);
+    let range = lightness_range_for_chroma_hue(chroma, hue);
+
+    if !hue.is_finite() {
+        assert!(cusp.chroma.is_nan(), "{} -> {:?}", hue, cusp);
+        assert_eq!(range, None, "{:?}", input);
+        return;
+    }
+    if !chroma.is_finite() {
+        assert_eq!(range, None, "{:?}", input);
+    }
+    assert!(cusp.is_finite(), "{} -> {:?}", hue, cusp);
+    assert!(
+        (0.0..=100.0).contains(&cusp.lightness),
+        "{} -> {:?}",
+        hue,
+        cusp
+    );
+    if let Some((min, max)) = range {
+        assert!(
+            0.0 <= min && min <= max && max <= 100.0,
+            "{:?} -> {:?}",
+            input,
+            range
+        );
+    }
+});
@@ -0,0 +1,16 @@
+//! Hex parsing on arbitrary strings. Nothing may panic, and whatever parses
+//! must print back the same, modulo case.
+
+#![no_main]
+
+use hsluv::*;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|hex: &str| {
+    let _ = hex_to_hsluv(hex);
+    let _ = hex_to_hpluv(hex);
+    if let Ok(rgb) = Rgb::from_hex(hex) {
+        assert!(Rgb::new(rgb.red, rgb.green, rgb.blue).is_ok(), "{:?}", hex);
+        assert_eq!(rgb.to_hex(), hex.to_ascii_lowercase());
+    }
+});
@@ -0,0 +1,55 @@
+//! Every conversion into `Rgb` from arbitrary Hsluv and Hpluv components.
+//! Nothing may panic, and valid components must give finite, in-gamut
+//! colors.
+
+#![no_main]
+
+use hsluv::*;
+use libfuzzer_sys::fuzz_target;
+
+// How far past the gamut's edge rounding may push a channel.
+const TOLERANCE: f64 = 1e-9;
+
+fn check_rgb(from: &dyn core::fmt::Debug, rgb: Rgb) {
+    for channel in [rgb.red, rgb.green, rgb.blue] {
+        assert!(
+            (-TOLERANCE..=1.0 + TOLERANCE).contains(&channel),
+            "{:?} -> {:?}",
+            from,
+            rgb
+        );
+    }
+}
+
+fuzz_target!(|input: (f64, f64, f64)| {
+    let (hue, saturation, lightness) = input;
+    let hsluv = Hsluv {
+        hue,
+        saturation,
+        lightness,
+    };
+    let hpluv = Hpluv {
+        hue,
+        saturation,
+        lightness,
+    };
+    let from_hsluv = Rgb::from(hsluv);
+    let from_hpluv = Rgb::from(hpluv);
+    let _ = hsluv_to_rgb(hue, saturation, lightness);
+    let _ = hpluv_to_rgb(hue, saturation, lightness);
+    let _ = hsluv_to_hex(hue, saturation, lightness);
+    let _ = hpluv_to_hex(hue, saturation, lightness);
+    let _ = Rgb::from_hsluv_const(hsluv);
+    let _ = Rgb::from_hpluv_const(hpluv);
+    let _ = Rgb::checked_from(hsluv);
+    let _ = Rgb::checked_from(hpluv);
+
+    if Hsluv::new(hue, saturation, lightness).is_ok() {
+        check_rgb(&hsluv, from_hsluv);
+        check_rgb(&hsluv, Rgb::from_hsluv_const(hsluv));
+    }
+    if Hpluv::new(hue, saturation, lightness).is_ok() {
+        check_rgb(&hpluv, from_hpluv);
+        check_rgb(&hpluv, Rgb::from_hpluv_const(hpluv));
+    }
+});
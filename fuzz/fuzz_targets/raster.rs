@@ -0,0 +1,39 @@
+//! The picker renderers on arbitrary sizes and components, including empty
+//! buffers. Nothing may panic, and every row of a lightness strip must match
+//! the first.
+
+#![no_main]
+
+use hsluv::*;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (u8, u8, bool, f64, f64, f64, Option<[u8; 4]>)| {
+    let (width, height, hpluv, hue, saturation, lightness, out_of_gamut) = input;
+    // Small enough to keep each run fast, large enough to cover odd shapes.
+    let (width, height) = (usize::from(width % 48), usize::from(height % 48));
+    let space = if hpluv {
+        PickerSpace::Hpluv
+    } else {
+        PickerSpace::Hsluv
+    };
+    let mut pixels = vec![0; width * height * 4];
+
+    render_hue_saturation_disk(&mut pixels, width, height, space, lightness, out_of_gamut);
+    render_luv_plane(&mut pixels, width, height, lightness, out_of_gamut);
+    render_gamut_polygon(&mut pixels, width, height, lightness, [0; 4]);
+    render_lightness_strip(
+        &mut pixels,
+        width,
+        height,
+        space,
+        hue,
+        saturation,
+        out_of_gamut,
+    );
+    if width > 0 {
+        let mut rows = pixels.chunks_exact(width * 4);
+        if let Some(first) = rows.next() {
+            assert!(rows.all(|row| row == first), "{:?}", input);
+        }
+    }
+});
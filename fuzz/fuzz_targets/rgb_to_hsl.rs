@@ -0,0 +1,62 @@
+//! Every conversion out of `Rgb` on arbitrary channels. Nothing may panic,
+//! and in-gamut colors must come out finite and in range.
+
+#![no_main]
+
+use hsluv::*;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (f64, f64, f64)| {
+    let (red, green, blue) = input;
+    let rgb = Rgb { red, green, blue };
+    let xyz = Xyz::from(rgb);
+    let luv = Luv::from(xyz);
+    let lch = Lch::from(luv);
+    let hsluv = Hsluv::from(lch);
+    let hpluv = Hpluv::from(lch);
+    let _ = rgb_to_hsluv(red, green, blue);
+    let _ = rgb_to_hpluv(red, green, blue);
+    let _ = Hsluv::from_rgb_const(rgb);
+    let _ = Hpluv::from_rgb_const(rgb);
+    let _ = Hsluv::checked_from(rgb);
+    let _ = Hpluv::checked_from(rgb);
+    let _ = rgb.to_rgb8();
+    let _ = rgb.to_hex();
+
+    if Rgb::new(red, green, blue).is_err() {
+        return;
+    }
+    assert!(xyz.is_finite(), "{:?} -> {:?}", rgb, xyz);
+    assert!(luv.is_finite(), "{:?} -> {:?}", rgb, luv);
+    assert!(lch.is_finite(), "{:?} -> {:?}", rgb, lch);
+    for (space, (hue, saturation, lightness)) in [("Hsluv", hsluv.hsl()), ("Hpluv", hpluv.hsl())] {
+        assert!(
+            (0.0..360.0).contains(&hue),
+            "{:?} -> {} hue {}",
+            rgb,
+            space,
+            hue
+        );
+        assert!(
+            saturation.is_finite() && saturation >= 0.0,
+            "{:?} -> {} saturation {}",
+            rgb,
+            space,
+            saturation
+        );
+        assert!(
+            (0.0..=100.0 + 1e-9).contains(&lightness),
+            "{:?} -> {} lightness {}",
+            rgb,
+            space,
+            lightness
+        );
+    }
+    // Hpluv saturation can pass 100; Hsluv's covers the whole gamut.
+    assert!(
+        hsluv.saturation <= 100.0 + 1e-6,
+        "{:?} -> Hsluv saturation {}",
+        rgb,
+        hsluv.saturation
+    );
+});
@@ -0,0 +1,43 @@
+//! `FromStr` on arbitrary strings for every color type. Nothing may panic,
+//! and whatever parses must print and parse back to the same components.
+
+#![no_main]
+
+use core::fmt::{Debug, Display};
+use core::str::FromStr;
+use hsluv::*;
+use libfuzzer_sys::fuzz_target;
+
+fn check<T>(s: &str, components: fn(&T) -> (f64, f64, f64))
+where
+    T: FromStr<Err = ParseColorError> + Display + Debug,
+{
+    let color = match s.parse::<T>() {
+        Ok(color) => color,
+        Err(error) => {
+            let _ = error.to_string();
+            return;
+        }
+    };
+    let _ = format!("{:.3}", color);
+    let printed = color.to_string();
+    let reparsed = printed.parse::<T>().unwrap();
+    let same = |a: f64, b: f64| a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan());
+    let (a, b) = (components(&color), components(&reparsed));
+    assert!(
+        same(a.0, b.0) && same(a.1, b.1) && same(a.2, b.2),
+        "{:?} -> {} -> {:?}",
+        s,
+        printed,
+        reparsed
+    );
+}
+
+fuzz_target!(|s: &str| {
+    check::<Rgb>(s, Rgb::rgb);
+    check::<Xyz>(s, Xyz::xyz);
+    check::<Luv>(s, Luv::luv);
+    check::<Lch>(s, Lch::lch);
+    check::<Hsluv>(s, Hsluv::hsl);
+    check::<Hpluv>(s, Hpluv::hsl);
+});
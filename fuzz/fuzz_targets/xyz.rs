@@ -0,0 +1,41 @@
+//! Conversions between `Xyz`, `Luv` and `Lch` on arbitrary components.
+//! Nothing may panic, and a valid `Xyz` must stay finite the whole way.
+
+#![no_main]
+
+use hsluv::*;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (f64, f64, f64)| {
+    let (a, b, c) = input;
+    let _ = Rgb::from(Xyz::from(Luv {
+        lightness: a,
+        u: b,
+        v: c,
+    }));
+    let _ = Luv::from(Lch {
+        lightness: a,
+        chroma: b,
+        hue: c,
+    });
+    let _ = Hsluv::from(Lch {
+        lightness: a,
+        chroma: b,
+        hue: c,
+    });
+    let _ = Hpluv::from(Lch {
+        lightness: a,
+        chroma: b,
+        hue: c,
+    });
+
+    if let Ok(xyz) = Xyz::new(a, b, c) {
+        let luv = Luv::from(xyz);
+        let lch = Lch::from(luv);
+        let rgb = Rgb::from(xyz);
+        assert!(luv.is_finite(), "{:?} -> {:?}", xyz, luv);
+        assert!(lch.is_finite(), "{:?} -> {:?}", xyz, lch);
+        assert!(rgb.is_finite(), "{:?} -> {:?}", xyz, rgb);
+        assert!((0.0..360.0).contains(&lch.hue), "{:?} -> {:?}", xyz, lch);
+    }
+});
@@ -0,0 +1,87 @@
+#ifndef HSLUV_H
+#define HSLUV_H
+
+/* Generated by cbindgen from the hsluv crate's capi module. Don't edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/*
+ # Safety
+
+ `pr`, `pg` and `pb` must be valid for writes.
+ */
+void hsluv2rgb(double h, double s, double l, double *pr, double *pg, double *pb);
+
+/*
+ # Safety
+
+ `ph`, `ps` and `pl` must be valid for writes.
+ */
+void rgb2hsluv(double r, double g, double b, double *ph, double *ps, double *pl);
+
+/*
+ # Safety
+
+ `pr`, `pg` and `pb` must be valid for writes.
+ */
+void hpluv2rgb(double h, double s, double l, double *pr, double *pg, double *pb);
+
+/*
+ # Safety
+
+ `ph`, `ps` and `pl` must be valid for writes.
+ */
+void rgb2hpluv(double r, double g, double b, double *ph, double *ps, double *pl);
+
+/*
+ Writes a NUL-terminated lowercase `#rrggbb`.
+
+ # Safety
+
+ `hex` must be valid for writes of 8 bytes.
+ */
+void hsluv2hex(double h, double s, double l, char *hex);
+
+/*
+ Writes a NUL-terminated lowercase `#rrggbb`.
+
+ # Safety
+
+ `hex` must be valid for writes of 8 bytes.
+ */
+void hpluv2hex(double h, double s, double l, char *hex);
+
+/*
+ Parses `#rrggbb` in either case. Returns 0, or -1 without writing
+ anything if `hex` isn't a color.
+
+ # Safety
+
+ `hex` must be a NUL-terminated string, and `ph`, `ps` and `pl` must be
+ valid for writes.
+ */
+int hex2hsluv(const char *hex, double *ph, double *ps, double *pl);
+
+/*
+ Parses `#rrggbb` in either case. Returns 0, or -1 without writing
+ anything if `hex` isn't a color.
+
+ # Safety
+
+ `hex` must be a NUL-terminated string, and `ph`, `ps` and `pl` must be
+ valid for writes.
+ */
+int hex2hpluv(const char *hex, double *ph, double *ps, double *pl);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* HSLUV_H */
@@ -0,0 +1,8 @@
+//! Builds the `capi` feature of [`hsluv`] into
+//! `libhsluv_capi.so`/`libhsluv_capi.a`, declared by `include/hsluv.h`. The
+//! functions match hsluv-c's, so C and C++ code can link this in its place;
+//! install the library as `libhsluv.so`/`libhsluv.a` to keep `-lhsluv`
+//! working. It isn't named `hsluv` here, as that would collide with the
+//! `hsluv` crate's own output and docs.
+
+pub use hsluv::capi::*;
@@ -0,0 +1,60 @@
+//! Builds `tests/snapshot.c` against the static library and runs it over
+//! `snapshot-rev4.json`. Skipped when there's no C compiler; set `CC` to pick
+//! one.
+
+use std::{env, fs, path::Path, process::Command};
+
+#[test]
+fn test_c_snapshot() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let tmp_dir = Path::new(env!("CARGO_TARGET_TMPDIR"));
+    // target/<profile>/deps/c-<hash> -> target/<profile>/deps/libhsluv_capi.a,
+    // built alongside the rlib this test depends on.
+    let library = env::current_exe()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .join("libhsluv_capi.a");
+
+    let snapshot = fs::read_to_string(manifest_dir.join("../snapshots/snapshot-rev4.json"))
+        .expect("Snapshot file not present");
+    let snapshot = json::parse(&snapshot).expect("Can't parse snapshot");
+    let mut flat = String::new();
+    for (color, values) in snapshot.entries() {
+        flat.push_str(color);
+        for space in ["rgb", "hsluv", "hpluv"].iter() {
+            for i in 0..3 {
+                flat.push_str(&format!(" {:?}", values[*space][i].as_f64().unwrap()));
+            }
+        }
+        flat.push('\n');
+    }
+    let flat_path = tmp_dir.join("snapshot-rev4.txt");
+    fs::write(&flat_path, flat).unwrap();
+
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".into());
+    let exe = tmp_dir.join("snapshot");
+    let compiled = Command::new(&compiler)
+        .args(["-std=c99", "-Wall", "-Wextra", "-Werror", "-I"])
+        .arg(manifest_dir.join("include"))
+        .arg(manifest_dir.join("tests/snapshot.c"))
+        .arg(&library)
+        .args(["-lm", "-lpthread", "-ldl", "-o"])
+        .arg(&exe)
+        .status();
+    match compiled {
+        Ok(status) => assert!(status.success(), "{} failed", compiler),
+        Err(error) => {
+            eprintln!("skipping, can't run {}: {}", compiler, error);
+            return;
+        }
+    }
+
+    let output = Command::new(&exe).arg(&flat_path).output().unwrap();
+    print!("{}", String::from_utf8_lossy(&output.stdout));
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
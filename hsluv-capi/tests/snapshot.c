@@ -0,0 +1,89 @@
+/*
+ * Checks the C API against a flattened snapshot, one color per line:
+ * the hex key, then RGB, HSLuv and HPLuv components.
+ */
+
+#include <math.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "hsluv.h"
+
+#define MAX_DIFF 0.00000001
+
+static int failures = 0;
+
+static void check_eq(const char *color, const char *format, double expected, double actual) {
+    if (!(fabs(expected - actual) < MAX_DIFF)) {
+        fprintf(stderr, "%s %s: expected %.17g, got %.17g\n", color, format, expected, actual);
+        failures++;
+    }
+}
+
+static void check_triple(const char *color, const char *format, const double expected[3],
+                         const double actual[3]) {
+    int i;
+    for (i = 0; i < 3; i++) {
+        check_eq(color, format, expected[i], actual[i]);
+    }
+}
+
+static void check_hex(const char *color, const char *format, const char *actual) {
+    if (strcmp(color, actual) != 0) {
+        fprintf(stderr, "%s %s: got %s\n", color, format, actual);
+        failures++;
+    }
+}
+
+int main(int argc, char **argv) {
+    FILE *snapshot;
+    char color[8];
+    double rgb[3], hsluv[3], hpluv[3], out[3];
+    char hex[8];
+    int colors = 0;
+
+    if (argc != 2 || !(snapshot = fopen(argv[1], "r"))) {
+        fprintf(stderr, "usage: %s SNAPSHOT\n", argv[0]);
+        return 2;
+    }
+
+    while (fscanf(snapshot, "%7s %lf %lf %lf %lf %lf %lf %lf %lf %lf", color, &rgb[0], &rgb[1],
+                  &rgb[2], &hsluv[0], &hsluv[1], &hsluv[2], &hpluv[0], &hpluv[1],
+                  &hpluv[2]) == 10) {
+        hsluv2rgb(hsluv[0], hsluv[1], hsluv[2], &out[0], &out[1], &out[2]);
+        check_triple(color, "hsluv2rgb", rgb, out);
+        hpluv2rgb(hpluv[0], hpluv[1], hpluv[2], &out[0], &out[1], &out[2]);
+        check_triple(color, "hpluv2rgb", rgb, out);
+        rgb2hsluv(rgb[0], rgb[1], rgb[2], &out[0], &out[1], &out[2]);
+        check_triple(color, "rgb2hsluv", hsluv, out);
+        rgb2hpluv(rgb[0], rgb[1], rgb[2], &out[0], &out[1], &out[2]);
+        check_triple(color, "rgb2hpluv", hpluv, out);
+
+        hsluv2hex(hsluv[0], hsluv[1], hsluv[2], hex);
+        check_hex(color, "hsluv2hex", hex);
+        hpluv2hex(hpluv[0], hpluv[1], hpluv[2], hex);
+        check_hex(color, "hpluv2hex", hex);
+        if (hex2hsluv(color, &out[0], &out[1], &out[2]) != 0) {
+            fprintf(stderr, "%s hex2hsluv: rejected\n", color);
+            failures++;
+        }
+        check_triple(color, "hex2hsluv", hsluv, out);
+        if (hex2hpluv(color, &out[0], &out[1], &out[2]) != 0) {
+            fprintf(stderr, "%s hex2hpluv: rejected\n", color);
+            failures++;
+        }
+        check_triple(color, "hex2hpluv", hpluv, out);
+
+        colors++;
+    }
+    fclose(snapshot);
+
+    if (hex2hsluv("#11ee0", &out[0], &out[1], &out[2]) != -1 ||
+        hex2hpluv("11ee00", &out[0], &out[1], &out[2]) != -1) {
+        fprintf(stderr, "invalid hex accepted\n");
+        failures++;
+    }
+
+    printf("%d colors, %d failures\n", colors, failures);
+    return colors == 0 || failures != 0;
+}
@@ -0,0 +1,201 @@
+//! Compile-time color literals for [`hsluv`](mod@hsluv).
+//!
+//! Each macro checks its components with the same constructor you would call
+//! at runtime and expands to a struct literal, so invalid colors are compile
+//! errors instead of `Err`s:
+//!
+//! ```
+//! use hsluv::{Hpluv, Hsluv, Rgb};
+//! use hsluv_macros::{hpluv, hsluv, rgb};
+//!
+//! const ACCENT: Hsluv = hsluv!(210, 80, 50);
+//! const PASTEL: Hpluv = hpluv!(40.5, 90, 85);
+//! const GREEN: Rgb = rgb!("#11ee00");
+//! const GRAY: Rgb = rgb!(0.5, 0.5, 0.5);
+//! ```
+//!
+//! A single hex string is accepted by all three macros, and converted at
+//! compile time for `hsluv!` and `hpluv!`.
+//!
+//! ```compile_fail
+//! let _ = hsluv_macros::hsluv!(400, 80, 50); // HslBounds::Hue
+//! ```
+//!
+//! ```compile_fail
+//! let _ = hsluv_macros::rgb!(0.5, 1.5, 0.5); // RgbBounds::Green
+//! ```
+//!
+//! ```compile_fail
+//! let _ = hsluv_macros::rgb!("#11ee0"); // HexError::Length
+//! ```
+
+extern crate proc_macro;
+
+use hsluv::{Hpluv, Hsluv, Rgb};
+use proc_macro::{Delimiter, TokenStream, TokenTree};
+
+enum Args {
+    Hex(String),
+    Components(f64, f64, f64),
+}
+
+fn parse_number(literal: &str) -> Option<f64> {
+    let literal = literal.replace('_', "");
+    let digits = [
+        "f32", "f64", "u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64",
+    ]
+    .iter()
+    .find_map(|suffix| literal.strip_suffix(suffix))
+    .unwrap_or(&literal);
+    digits.parse().ok()
+}
+
+fn parse_string(literal: &str) -> Option<String> {
+    literal
+        .strip_prefix('"')?
+        .strip_suffix('"')
+        .map(String::from)
+}
+
+// Literals passed in through `macro_rules!` fragments arrive wrapped in
+// invisible groups.
+fn flatten(input: TokenStream, tokens: &mut Vec<TokenTree>) {
+    for token in input {
+        match token {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+                flatten(group.stream(), tokens)
+            }
+            token => tokens.push(token),
+        }
+    }
+}
+
+fn parse_args(name: &str, input: TokenStream) -> Result<Args, String> {
+    let mut tokens = Vec::new();
+    flatten(input, &mut tokens);
+
+    let mut values = Vec::new();
+    let mut strings = Vec::new();
+    let mut negative = false;
+    let mut expect_comma = false;
+    for token in tokens {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' && expect_comma => {
+                expect_comma = false;
+            }
+            TokenTree::Punct(punct) if punct.as_char() == '-' && !negative && !expect_comma => {
+                negative = true;
+            }
+            TokenTree::Literal(literal) if !expect_comma => {
+                let literal = literal.to_string();
+                if let Some(value) = parse_number(&literal) {
+                    values.push(if negative { -value } else { value });
+                } else if let (Some(string), false) = (parse_string(&literal), negative) {
+                    strings.push(string);
+                } else {
+                    return Err(format!("{}!: expected a number, got {}", name, literal));
+                }
+                negative = false;
+                expect_comma = true;
+            }
+            token => return Err(format!("{}!: unexpected `{}`", name, token)),
+        }
+    }
+    match (&values[..], &strings[..]) {
+        (&[a, b, c], []) => Ok(Args::Components(a, b, c)),
+        ([], [hex]) => Ok(Args::Hex(hex.clone())),
+        _ => Err(format!(
+            "{}!: expected three components or one \"#rrggbb\" string",
+            name
+        )),
+    }
+}
+
+fn parse_hex(name: &str, hex: &str) -> Result<Rgb, String> {
+    Rgb::from_hex(hex).map_err(|error| {
+        format!(
+            "{}!: Rgb::from_hex({:?}) failed with HexError::{:?}",
+            name, hex, error
+        )
+    })
+}
+
+fn expand(result: Result<String, String>) -> TokenStream {
+    match result {
+        Ok(expansion) => expansion,
+        Err(message) => format!("compile_error!({:?})", message),
+    }
+    .parse()
+    .expect("generated tokens should parse")
+}
+
+fn hsl_literal(ty: &str, (hue, saturation, lightness): (f64, f64, f64)) -> String {
+    format!(
+        "::hsluv::{} {{ hue: {:?}_f64, saturation: {:?}_f64, lightness: {:?}_f64 }}",
+        ty, hue, saturation, lightness
+    )
+}
+
+/// An `hsluv::Hsluv` from `hue, saturation, lightness` or a `"#rrggbb"`
+/// string.
+#[proc_macro]
+pub fn hsluv(input: TokenStream) -> TokenStream {
+    expand(parse_args("hsluv", input).and_then(|args| {
+        match args {
+            Args::Components(hue, saturation, lightness) => Hsluv::new(hue, saturation, lightness)
+                .map(|hsluv| hsl_literal("Hsluv", hsluv.hsl()))
+                .map_err(|error| {
+                    format!(
+                        "hsluv!: Hsluv::new({:?}, {:?}, {:?}) failed with HslBounds::{:?}",
+                        hue, saturation, lightness, error
+                    )
+                }),
+            Args::Hex(hex) => {
+                parse_hex("hsluv", &hex).map(|rgb| hsl_literal("Hsluv", Hsluv::from(rgb).hsl()))
+            }
+        }
+    }))
+}
+
+/// An `hsluv::Hpluv` from `hue, saturation, lightness` or a `"#rrggbb"`
+/// string.
+#[proc_macro]
+pub fn hpluv(input: TokenStream) -> TokenStream {
+    expand(parse_args("hpluv", input).and_then(|args| {
+        match args {
+            Args::Components(hue, saturation, lightness) => Hpluv::new(hue, saturation, lightness)
+                .map(|hpluv| hsl_literal("Hpluv", hpluv.hsl()))
+                .map_err(|error| {
+                    format!(
+                        "hpluv!: Hpluv::new({:?}, {:?}, {:?}) failed with HslBounds::{:?}",
+                        hue, saturation, lightness, error
+                    )
+                }),
+            Args::Hex(hex) => {
+                parse_hex("hpluv", &hex).map(|rgb| hsl_literal("Hpluv", Hpluv::from(rgb).hsl()))
+            }
+        }
+    }))
+}
+
+/// An `hsluv::Rgb` from `red, green, blue` in 0..=1 or a `"#rrggbb"` string.
+#[proc_macro]
+pub fn rgb(input: TokenStream) -> TokenStream {
+    expand(parse_args("rgb", input).and_then(|args| {
+        match args {
+            Args::Components(red, green, blue) => Rgb::new(red, green, blue).map_err(|error| {
+                format!(
+                    "rgb!: Rgb::new({:?}, {:?}, {:?}) failed with RgbBounds::{:?}",
+                    red, green, blue, error
+                )
+            }),
+            Args::Hex(hex) => parse_hex("rgb", &hex),
+        }
+        .map(|rgb| {
+            format!(
+                "::hsluv::Rgb {{ red: {:?}_f64, green: {:?}_f64, blue: {:?}_f64 }}",
+                rgb.red, rgb.green, rgb.blue
+            )
+        })
+    }))
+}
@@ -0,0 +1,40 @@
+use hsluv::{Hpluv, Hsluv, Rgb};
+use hsluv_macros::{hpluv, hsluv, rgb};
+
+const ACCENT: Hsluv = hsluv!(210, 80, 50);
+
+macro_rules! through_fragments {
+    ($hue:literal, $saturation:expr, $lightness:tt) => {
+        hsluv!($hue, $saturation, $lightness)
+    };
+}
+
+#[test]
+fn test_hsluv() {
+    assert_eq!(ACCENT, Hsluv::new(210.0, 80.0, 50.0).unwrap());
+    assert_eq!(
+        hsluv!(0.5, 1e1, 99_f64),
+        Hsluv::new(0.5, 10.0, 99.0).unwrap()
+    );
+    assert_eq!(through_fragments!(210, 80, 50), ACCENT);
+    assert_eq!(
+        hsluv!("#11ee00"),
+        Hsluv::from(Rgb::from_hex("#11ee00").unwrap())
+    );
+}
+
+#[test]
+fn test_hpluv() {
+    assert_eq!(hpluv!(360, 100, 0), Hpluv::new(360.0, 100.0, 0.0).unwrap());
+    assert_eq!(
+        hpluv!("#11EE00"),
+        Hpluv::from(Rgb::from_hex("#11ee00").unwrap())
+    );
+}
+
+#[test]
+fn test_rgb() {
+    assert_eq!(rgb!("#11ee00"), Rgb::from_rgb8([0x11, 0xee, 0x00]));
+    assert_eq!(rgb!(0.25, 0.5, 1), Rgb::new(0.25, 0.5, 1.0).unwrap());
+    assert_eq!(rgb!(-0.0, 0, 0).red, 0.0);
+}
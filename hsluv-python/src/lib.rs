@@ -0,0 +1,154 @@
+//! Builds the `hsluv_rs` Python extension module with maturin. The scalar
+//! functions take the same arguments as the pure-Python `hsluv` package, so
+//! `import hsluv_rs as hsluv` works in its place, but return tuples instead of
+//! lists. The `_array` functions convert NumPy arrays of shape `(..., 3)` in
+//! one call, without holding the GIL.
+
+use hsluv::{HexError, Hpluv, Hsluv, Rgb};
+use numpy::ndarray::ArrayD;
+use numpy::{IntoPyArray, PyArrayDyn, PyReadonlyArrayDyn};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+type Triple = (f64, f64, f64);
+
+#[pyfunction]
+fn hsluv_to_rgb(hsl: [f64; 3]) -> Triple {
+    let [h, s, l] = hsl;
+    hsluv::hsluv_to_rgb(h, s, l)
+}
+
+#[pyfunction]
+fn rgb_to_hsluv(rgb: [f64; 3]) -> Triple {
+    let [r, g, b] = rgb;
+    hsluv::rgb_to_hsluv(r, g, b)
+}
+
+#[pyfunction]
+fn hpluv_to_rgb(hsl: [f64; 3]) -> Triple {
+    let [h, s, l] = hsl;
+    hsluv::hpluv_to_rgb(h, s, l)
+}
+
+#[pyfunction]
+fn rgb_to_hpluv(rgb: [f64; 3]) -> Triple {
+    let [r, g, b] = rgb;
+    hsluv::rgb_to_hpluv(r, g, b)
+}
+
+#[pyfunction]
+fn hsluv_to_hex(hsl: [f64; 3]) -> String {
+    let [h, s, l] = hsl;
+    hsluv::hsluv_to_hex(h, s, l)
+}
+
+#[pyfunction]
+fn hpluv_to_hex(hsl: [f64; 3]) -> String {
+    let [h, s, l] = hsl;
+    hsluv::hpluv_to_hex(h, s, l)
+}
+
+fn hex_error(hex: &str, err: HexError) -> PyErr {
+    PyValueError::new_err(format!("invalid hex color {:?}: {:?}", hex, err))
+}
+
+/// Raises `ValueError` unless `hex` is `#rrggbb`.
+#[pyfunction]
+fn hex_to_hsluv(hex: &str) -> PyResult<Triple> {
+    hsluv::hex_to_hsluv(hex).map_err(|err| hex_error(hex, err))
+}
+
+/// Raises `ValueError` unless `hex` is `#rrggbb`.
+#[pyfunction]
+fn hex_to_hpluv(hex: &str) -> PyResult<Triple> {
+    hsluv::hex_to_hpluv(hex).map_err(|err| hex_error(hex, err))
+}
+
+// Converts every color along the last axis, which must have length 3.
+fn convert_array<'py>(
+    py: Python<'py>,
+    colors: PyReadonlyArrayDyn<'py, f64>,
+    convert: impl FnOnce(&[f64], &mut [f64]) + Send,
+) -> PyResult<Bound<'py, PyArrayDyn<f64>>> {
+    let colors = colors.as_array();
+    if colors.shape().last() != Some(&3) {
+        return Err(PyValueError::new_err(format!(
+            "expected an array of shape (..., 3), got {:?}",
+            colors.shape()
+        )));
+    }
+    let colors = colors.as_standard_layout();
+    let mut out = ArrayD::zeros(colors.shape());
+    let (input, output) = (colors.as_slice().unwrap(), out.as_slice_mut().unwrap());
+    py.detach(|| convert(input, output));
+    Ok(out.into_pyarray(py))
+}
+
+#[pyfunction]
+fn hsluv_to_rgb_array<'py>(
+    py: Python<'py>,
+    hsluv: PyReadonlyArrayDyn<'py, f64>,
+) -> PyResult<Bound<'py, PyArrayDyn<f64>>> {
+    convert_array(py, hsluv, |hsluv, rgb| {
+        hsluv::hsluv_to_rgb_slice(
+            Hsluv::from_slice(hsluv).unwrap(),
+            Rgb::from_mut_slice(rgb).unwrap(),
+        )
+    })
+}
+
+#[pyfunction]
+fn rgb_to_hsluv_array<'py>(
+    py: Python<'py>,
+    rgb: PyReadonlyArrayDyn<'py, f64>,
+) -> PyResult<Bound<'py, PyArrayDyn<f64>>> {
+    convert_array(py, rgb, |rgb, hsluv| {
+        hsluv::rgb_to_hsluv_slice(
+            Rgb::from_slice(rgb).unwrap(),
+            Hsluv::from_mut_slice(hsluv).unwrap(),
+        )
+    })
+}
+
+#[pyfunction]
+fn hpluv_to_rgb_array<'py>(
+    py: Python<'py>,
+    hpluv: PyReadonlyArrayDyn<'py, f64>,
+) -> PyResult<Bound<'py, PyArrayDyn<f64>>> {
+    convert_array(py, hpluv, |hpluv, rgb| {
+        hsluv::hpluv_to_rgb_slice(
+            Hpluv::from_slice(hpluv).unwrap(),
+            Rgb::from_mut_slice(rgb).unwrap(),
+        )
+    })
+}
+
+#[pyfunction]
+fn rgb_to_hpluv_array<'py>(
+    py: Python<'py>,
+    rgb: PyReadonlyArrayDyn<'py, f64>,
+) -> PyResult<Bound<'py, PyArrayDyn<f64>>> {
+    convert_array(py, rgb, |rgb, hpluv| {
+        hsluv::rgb_to_hpluv_slice(
+            Rgb::from_slice(rgb).unwrap(),
+            Hpluv::from_mut_slice(hpluv).unwrap(),
+        )
+    })
+}
+
+#[pymodule]
+fn hsluv_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(hsluv_to_rgb, m)?)?;
+    m.add_function(wrap_pyfunction!(rgb_to_hsluv, m)?)?;
+    m.add_function(wrap_pyfunction!(hpluv_to_rgb, m)?)?;
+    m.add_function(wrap_pyfunction!(rgb_to_hpluv, m)?)?;
+    m.add_function(wrap_pyfunction!(hsluv_to_hex, m)?)?;
+    m.add_function(wrap_pyfunction!(hpluv_to_hex, m)?)?;
+    m.add_function(wrap_pyfunction!(hex_to_hsluv, m)?)?;
+    m.add_function(wrap_pyfunction!(hex_to_hpluv, m)?)?;
+    m.add_function(wrap_pyfunction!(hsluv_to_rgb_array, m)?)?;
+    m.add_function(wrap_pyfunction!(rgb_to_hsluv_array, m)?)?;
+    m.add_function(wrap_pyfunction!(hpluv_to_rgb_array, m)?)?;
+    m.add_function(wrap_pyfunction!(rgb_to_hpluv_array, m)?)?;
+    Ok(())
+}
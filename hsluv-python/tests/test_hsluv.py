@@ -0,0 +1,96 @@
+"""Checks the extension module against snapshot-rev4.json.
+
+Build it into the current environment with `maturin develop`, then run
+`pytest`.
+"""
+
+import json
+from pathlib import Path
+
+import numpy as np
+import pytest
+
+import hsluv_rs
+
+MAX_DIFF = 1e-8
+
+SNAPSHOT_PATH = Path(__file__).parents[2] / "snapshots" / "snapshot-rev4.json"
+SNAPSHOT = json.loads(SNAPSHOT_PATH.read_text())
+
+
+def check_eq(color, space, expected, actual):
+    for i, (e, a) in enumerate(zip(expected, actual)):
+        assert abs(e - a) < MAX_DIFF, f"{color} {space}[{i}]: expected {e}, got {a}"
+
+
+def test_rgb_to_hsl():
+    for color, values in SNAPSHOT.items():
+        check_eq(color, "hsluv", values["hsluv"], hsluv_rs.rgb_to_hsluv(values["rgb"]))
+        check_eq(color, "hpluv", values["hpluv"], hsluv_rs.rgb_to_hpluv(values["rgb"]))
+
+
+def test_hsl_to_rgb():
+    for color, values in SNAPSHOT.items():
+        check_eq(color, "rgb", values["rgb"], hsluv_rs.hsluv_to_rgb(values["hsluv"]))
+        check_eq(color, "rgb", values["rgb"], hsluv_rs.hpluv_to_rgb(values["hpluv"]))
+
+
+def test_hex():
+    for color, values in SNAPSHOT.items():
+        assert hsluv_rs.hsluv_to_hex(values["hsluv"]) == color
+        assert hsluv_rs.hpluv_to_hex(values["hpluv"]) == color
+        check_eq(color, "hsluv", values["hsluv"], hsluv_rs.hex_to_hsluv(color))
+        check_eq(color, "hpluv", values["hpluv"], hsluv_rs.hex_to_hpluv(color))
+        check_eq(color, "hsluv", values["hsluv"], hsluv_rs.hex_to_hsluv(color.upper()))
+
+
+@pytest.mark.parametrize("hex", ["", "11ee00", "#11ee0", "#11ee00 ", "#11eg00"])
+def test_invalid_hex(hex):
+    with pytest.raises(ValueError):
+        hsluv_rs.hex_to_hsluv(hex)
+    with pytest.raises(ValueError):
+        hsluv_rs.hex_to_hpluv(hex)
+
+
+def test_sequences():
+    expected = hsluv_rs.hsluv_to_rgb((12.0, 50.0, 40.0))
+    assert hsluv_rs.hsluv_to_rgb([12.0, 50.0, 40.0]) == expected
+    assert hsluv_rs.hsluv_to_rgb([12, 50, 40]) == expected
+    assert hsluv_rs.hsluv_to_rgb(np.array([12.0, 50.0, 40.0])) == expected
+    with pytest.raises((TypeError, ValueError)):
+        hsluv_rs.hsluv_to_rgb([12.0, 50.0])
+
+
+def snapshot_array(space):
+    # 4096 colors as a 64x64 image.
+    return np.array([values[space] for values in SNAPSHOT.values()]).reshape(64, 64, 3)
+
+
+@pytest.mark.parametrize(
+    "convert, source, target",
+    [
+        (hsluv_rs.hsluv_to_rgb_array, "hsluv", "rgb"),
+        (hsluv_rs.hpluv_to_rgb_array, "hpluv", "rgb"),
+        (hsluv_rs.rgb_to_hsluv_array, "rgb", "hsluv"),
+        (hsluv_rs.rgb_to_hpluv_array, "rgb", "hpluv"),
+    ],
+)
+def test_arrays(convert, source, target):
+    source, target = snapshot_array(source), snapshot_array(target)
+    np.testing.assert_allclose(convert(source), target, rtol=0, atol=MAX_DIFF)
+    # Views that aren't C-contiguous are copied first.
+    np.testing.assert_allclose(
+        convert(source.transpose(1, 0, 2)), target.transpose(1, 0, 2), rtol=0, atol=MAX_DIFF
+    )
+    np.testing.assert_allclose(convert(source[::3, 1::2]), target[::3, 1::2], rtol=0, atol=MAX_DIFF)
+    assert convert(source[:0]).shape == (0, 64, 3)
+    assert convert(source[0, 0]).shape == (3,)
+
+
+def test_array_shape():
+    with pytest.raises(ValueError):
+        hsluv_rs.rgb_to_hsluv_array(np.zeros((4, 4)))
+    with pytest.raises(ValueError):
+        hsluv_rs.rgb_to_hsluv_array(np.zeros(()))
+    with pytest.raises(TypeError):
+        hsluv_rs.rgb_to_hsluv_array(np.zeros((4, 3), dtype=np.float32))
@@ -0,0 +1,159 @@
+//! Lightness, saturation and hue adjustments.
+//!
+//! Plain amounts are in the component's own units (lightness and saturation
+//! points, hue degrees). `_by` variants take a ratio and move that fraction of
+//! the remaining distance towards the limit, so `lighten_by(0.5)` halves the
+//! distance to white. The adjusted component is wrapped into 0..360 for hue
+//! and clamped to its valid range otherwise. The others are left alone, so an
+//! out-of-range input, such as an `Hpluv` with saturation 308, stays out of
+//! range; `clamp` it first where that matters. `Lch` results also have their
+//! chroma clamped to the sRGB gamut.
+
+use crate::{is_white_or_black, max_chroma_for_lightness_hue, wrap_hue, Hpluv, Hsluv, Lch};
+
+macro_rules! impl_hsl_adjustments {
+    ($t:ident) => {
+        impl $t {
+            pub fn lighten(self, amount: f64) -> $t {
+                $t {
+                    lightness: (self.lightness + amount).clamp(0.0, 100.0),
+                    ..self
+                }
+            }
+
+            pub fn darken(self, amount: f64) -> $t {
+                self.lighten(-amount)
+            }
+
+            pub fn lighten_by(self, ratio: f64) -> $t {
+                self.lighten((100.0 - self.lightness) * ratio)
+            }
+
+            pub fn darken_by(self, ratio: f64) -> $t {
+                self.darken(self.lightness * ratio)
+            }
+
+            pub fn saturate(self, amount: f64) -> $t {
+                $t {
+                    saturation: (self.saturation + amount).clamp(0.0, 100.0),
+                    ..self
+                }
+            }
+
+            pub fn desaturate(self, amount: f64) -> $t {
+                self.saturate(-amount)
+            }
+
+            pub fn saturate_by(self, ratio: f64) -> $t {
+                self.saturate((100.0 - self.saturation) * ratio)
+            }
+
+            pub fn desaturate_by(self, ratio: f64) -> $t {
+                self.desaturate(self.saturation * ratio)
+            }
+
+            pub fn rotate_hue(self, degrees: f64) -> $t {
+                $t {
+                    hue: wrap_hue(self.hue + degrees),
+                    ..self
+                }
+            }
+
+            pub fn complement(self) -> $t {
+                self.rotate_hue(180.0)
+            }
+
+            /// Mirrors lightness, turning white into black and back, while
+            /// keeping hue and saturation.
+            pub fn invert(self) -> $t {
+                $t {
+                    lightness: (100.0 - self.lightness).clamp(0.0, 100.0),
+                    ..self
+                }
+            }
+        }
+    };
+}
+
+impl_hsl_adjustments!(Hsluv);
+impl_hsl_adjustments!(Hpluv);
+
+// Chroma is clamped against the sRGB gamut at the adjusted lightness and hue,
+// which makes `Lch` adjustments lossy where `Hsluv` ones aren't.
+impl Lch {
+    fn max_chroma(&self) -> f64 {
+        // White and black only have gray
+        if is_white_or_black(self.lightness) {
+            0.0
+        } else {
+            max_chroma_for_lightness_hue(self.lightness, self.hue)
+        }
+    }
+
+    fn clamp_chroma(self) -> Lch {
+        Lch {
+            chroma: self.chroma.clamp(0.0, self.max_chroma()),
+            ..self
+        }
+    }
+
+    pub fn lighten(self, amount: f64) -> Lch {
+        Lch {
+            lightness: (self.lightness + amount).clamp(0.0, 100.0),
+            ..self
+        }
+        .clamp_chroma()
+    }
+
+    pub fn darken(self, amount: f64) -> Lch {
+        self.lighten(-amount)
+    }
+
+    pub fn lighten_by(self, ratio: f64) -> Lch {
+        self.lighten((100.0 - self.lightness) * ratio)
+    }
+
+    pub fn darken_by(self, ratio: f64) -> Lch {
+        self.darken(self.lightness * ratio)
+    }
+
+    pub fn saturate(self, amount: f64) -> Lch {
+        Lch {
+            chroma: self.chroma + amount,
+            ..self
+        }
+        .clamp_chroma()
+    }
+
+    pub fn desaturate(self, amount: f64) -> Lch {
+        self.saturate(-amount)
+    }
+
+    pub fn saturate_by(self, ratio: f64) -> Lch {
+        self.saturate((self.max_chroma() - self.chroma) * ratio)
+    }
+
+    pub fn desaturate_by(self, ratio: f64) -> Lch {
+        self.desaturate(self.chroma * ratio)
+    }
+
+    pub fn rotate_hue(self, degrees: f64) -> Lch {
+        Lch {
+            hue: wrap_hue(self.hue + degrees),
+            ..self
+        }
+        .clamp_chroma()
+    }
+
+    pub fn complement(self) -> Lch {
+        self.rotate_hue(180.0)
+    }
+
+    pub fn invert(self) -> Lch {
+        Lch {
+            lightness: (100.0 - self.lightness).clamp(0.0, 100.0),
+            ..self
+        }
+        .clamp_chroma()
+    }
+}
@@ -0,0 +1,97 @@
+//! Conversions over whole slices of colors.
+//!
+//! Results match the per-color `From` impls exactly. Runs of colors sharing a
+//! lightness (as in gradients and picker planes) share their gamut bounds.
+//! Every function panics if the input and output slices differ in length.
+//!
+//! The `rgb8_` functions take 8-bit channels, as `Rgb::from_rgb8` does, and
+//! with the `lut` feature linearize them by table lookup.
+
+use crate::{
+    get_bounds, hpluv_to_lch_with_bounds, hsluv_to_lch_with_bounds, linear_rgb_to_xyz,
+    rgb8_to_linear, Hpluv, Hsluv, Lch, Line, Luv, Rgb, Xyz,
+};
+
+fn check_lengths(input: usize, output: usize) {
+    assert_eq!(input, output, "batch input and output lengths differ");
+}
+
+struct BoundsCache {
+    lightness: f64,
+    bounds: [Line; 6],
+}
+
+impl BoundsCache {
+    fn new() -> BoundsCache {
+        BoundsCache {
+            lightness: 0.0,
+            bounds: get_bounds(0.0),
+        }
+    }
+
+    fn get(&mut self, lightness: f64) -> &[Line; 6] {
+        #[allow(clippy::float_cmp)]
+        if lightness != self.lightness {
+            self.lightness = lightness;
+            self.bounds = get_bounds(lightness);
+        }
+        &self.bounds
+    }
+}
+
+fn lch_to_rgb(lch: Lch) -> Rgb {
+    Rgb::from(Xyz::from(Luv::from(lch)))
+}
+
+pub fn hsluv_to_rgb_slice(hsluv: &[Hsluv], rgb: &mut [Rgb]) {
+    check_lengths(hsluv.len(), rgb.len());
+    let mut cache = BoundsCache::new();
+    for (hsluv, rgb) in hsluv.iter().zip(rgb) {
+        *rgb = lch_to_rgb(hsluv_to_lch_with_bounds(*hsluv, cache.get(hsluv.lightness)));
+    }
+}
+
+pub fn hpluv_to_rgb_slice(hpluv: &[Hpluv], rgb: &mut [Rgb]) {
+    check_lengths(hpluv.len(), rgb.len());
+    let mut cache = BoundsCache::new();
+    for (hpluv, rgb) in hpluv.iter().zip(rgb) {
+        *rgb = lch_to_rgb(hpluv_to_lch_with_bounds(*hpluv, cache.get(hpluv.lightness)));
+    }
+}
+
+pub fn rgb_to_hsluv_slice(rgb: &[Rgb], hsluv: &mut [Hsluv]) {
+    check_lengths(rgb.len(), hsluv.len());
+    for (rgb, hsluv) in rgb.iter().zip(hsluv) {
+        *hsluv = Hsluv::from(*rgb);
+    }
+}
+
+pub fn rgb_to_hpluv_slice(rgb: &[Rgb], hpluv: &mut [Hpluv]) {
+    check_lengths(rgb.len(), hpluv.len());
+    for (rgb, hpluv) in rgb.iter().zip(hpluv) {
+        *hpluv = Hpluv::from(*rgb);
+    }
+}
+
+fn rgb8_to_lch(rgb: [u8; 3]) -> Lch {
+    let xyz = linear_rgb_to_xyz((
+        rgb8_to_linear(rgb[0]),
+        rgb8_to_linear(rgb[1]),
+        rgb8_to_linear(rgb[2]),
+    ));
+    Lch::from(Luv::from(xyz))
+}
+
+pub fn rgb8_to_hsluv_slice(rgb: &[[u8; 3]], hsluv: &mut [Hsluv]) {
+    check_lengths(rgb.len(), hsluv.len());
+    for (rgb, hsluv) in rgb.iter().zip(hsluv) {
+        *hsluv = Hsluv::from(rgb8_to_lch(*rgb));
+    }
+}
+
+pub fn rgb8_to_hpluv_slice(rgb: &[[u8; 3]], hpluv: &mut [Hpluv]) {
+    check_lengths(rgb.len(), hpluv.len());
+    for (rgb, hpluv) in rgb.iter().zip(hpluv) {
+        *hpluv = Hpluv::from(rgb8_to_lch(*rgb));
+    }
+}
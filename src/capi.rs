@@ -0,0 +1,174 @@
+//! A C ABI matching hsluv-c, enabled by the `capi` feature. The
+//! `hsluv-capi` crate builds it into a shared or static library alongside a
+//! generated `hsluv.h`.
+//!
+//! Output pointers must be non-null and writable; like hsluv-c, nothing
+//! checks them.
+
+use crate::{hex_to_hpluv, hex_to_hsluv, hpluv_to_rgb, hsluv_to_rgb, rgb_to_hpluv, rgb_to_hsluv};
+use crate::{HexError, Hpluv, Hsluv, Rgb};
+use core::ffi::{c_char, c_int, CStr};
+
+unsafe fn write_triple((a, b, c): (f64, f64, f64), pa: *mut f64, pb: *mut f64, pc: *mut f64) {
+    *pa = a;
+    *pb = b;
+    *pc = c;
+}
+
+/// # Safety
+///
+/// `pr`, `pg` and `pb` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn hsluv2rgb(
+    h: f64,
+    s: f64,
+    l: f64,
+    pr: *mut f64,
+    pg: *mut f64,
+    pb: *mut f64,
+) {
+    write_triple(hsluv_to_rgb(h, s, l), pr, pg, pb);
+}
+
+/// # Safety
+///
+/// `ph`, `ps` and `pl` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn rgb2hsluv(
+    r: f64,
+    g: f64,
+    b: f64,
+    ph: *mut f64,
+    ps: *mut f64,
+    pl: *mut f64,
+) {
+    write_triple(rgb_to_hsluv(r, g, b), ph, ps, pl);
+}
+
+/// # Safety
+///
+/// `pr`, `pg` and `pb` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn hpluv2rgb(
+    h: f64,
+    s: f64,
+    l: f64,
+    pr: *mut f64,
+    pg: *mut f64,
+    pb: *mut f64,
+) {
+    write_triple(hpluv_to_rgb(h, s, l), pr, pg, pb);
+}
+
+/// # Safety
+///
+/// `ph`, `ps` and `pl` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn rgb2hpluv(
+    r: f64,
+    g: f64,
+    b: f64,
+    ph: *mut f64,
+    ps: *mut f64,
+    pl: *mut f64,
+) {
+    write_triple(rgb_to_hpluv(r, g, b), ph, ps, pl);
+}
+
+unsafe fn write_hex(rgb: Rgb, hex: *mut c_char) {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut out = [b'#', 0, 0, 0, 0, 0, 0, 0];
+    for (i, channel) in rgb.to_rgb8().iter().enumerate() {
+        out[1 + 2 * i] = DIGITS[usize::from(channel >> 4)];
+        out[2 + 2 * i] = DIGITS[usize::from(channel & 0xf)];
+    }
+    for (i, byte) in out.iter().enumerate() {
+        *hex.add(i) = *byte as c_char;
+    }
+}
+
+/// Writes a NUL-terminated lowercase `#rrggbb`.
+///
+/// # Safety
+///
+/// `hex` must be valid for writes of 8 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn hsluv2hex(h: f64, s: f64, l: f64, hex: *mut c_char) {
+    write_hex(
+        Rgb::from(Hsluv {
+            hue: h,
+            saturation: s,
+            lightness: l,
+        }),
+        hex,
+    );
+}
+
+/// Writes a NUL-terminated lowercase `#rrggbb`.
+///
+/// # Safety
+///
+/// `hex` must be valid for writes of 8 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn hpluv2hex(h: f64, s: f64, l: f64, hex: *mut c_char) {
+    write_hex(
+        Rgb::from(Hpluv {
+            hue: h,
+            saturation: s,
+            lightness: l,
+        }),
+        hex,
+    );
+}
+
+type ParseHex = fn(&str) -> Result<(f64, f64, f64), HexError>;
+
+unsafe fn read_hex(
+    hex: *const c_char,
+    convert: ParseHex,
+    ph: *mut f64,
+    ps: *mut f64,
+    pl: *mut f64,
+) -> c_int {
+    match CStr::from_ptr(hex).to_str().map(convert) {
+        Ok(Ok(hsl)) => {
+            write_triple(hsl, ph, ps, pl);
+            0
+        }
+        _ => -1,
+    }
+}
+
+/// Parses `#rrggbb` in either case. Returns 0, or -1 without writing
+/// anything if `hex` isn't a color.
+///
+/// # Safety
+///
+/// `hex` must be a NUL-terminated string, and `ph`, `ps` and `pl` must be
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn hex2hsluv(
+    hex: *const c_char,
+    ph: *mut f64,
+    ps: *mut f64,
+    pl: *mut f64,
+) -> c_int {
+    read_hex(hex, hex_to_hsluv, ph, ps, pl)
+}
+
+/// Parses `#rrggbb` in either case. Returns 0, or -1 without writing
+/// anything if `hex` isn't a color.
+///
+/// # Safety
+///
+/// `hex` must be a NUL-terminated string, and `ph`, `ps` and `pl` must be
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn hex2hpluv(
+    hex: *const c_char,
+    ph: *mut f64,
+    ps: *mut f64,
+    pl: *mut f64,
+) -> c_int {
+    read_hex(hex, hex_to_hpluv, ph, ps, pl)
+}
@@ -0,0 +1,39 @@
+//! Zero-copy views between slices of colors and slices of their components,
+//! enabled by the `bytemuck` feature.
+//!
+//! Every color type is `#[repr(C)]` with three `f64` fields, so a slice of
+//! colors is a slice of their components, three per color in field order.
+//! `f32` GPU buffers still need a conversion; the types aren't generic.
+
+use crate::{Hpluv, Hsluv, Lch, Luv, Rgb, Xyz};
+
+macro_rules! impl_slice_casts {
+    ($t:ident) => {
+        impl $t {
+            pub fn as_slice(colors: &[$t]) -> &[f64] {
+                bytemuck::cast_slice(colors)
+            }
+
+            pub fn as_mut_slice(colors: &mut [$t]) -> &mut [f64] {
+                bytemuck::cast_slice_mut(colors)
+            }
+
+            /// `None` unless `components` holds a whole number of colors.
+            pub fn from_slice(components: &[f64]) -> Option<&[$t]> {
+                bytemuck::try_cast_slice(components).ok()
+            }
+
+            /// `None` unless `components` holds a whole number of colors.
+            pub fn from_mut_slice(components: &mut [f64]) -> Option<&mut [$t]> {
+                bytemuck::try_cast_slice_mut(components).ok()
+            }
+        }
+    };
+}
+
+impl_slice_casts!(Rgb);
+impl_slice_casts!(Xyz);
+impl_slice_casts!(Luv);
+impl_slice_casts!(Lch);
+impl_slice_casts!(Hsluv);
+impl_slice_casts!(Hpluv);
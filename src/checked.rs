@@ -0,0 +1,87 @@
+//! Conversions that reject NaN and infinite components instead of passing
+//! them through.
+//!
+//! The `From` impls never fail: NaN in means NaN out. `CheckedFrom` checks
+//! both sides of the same conversion, so it also catches inputs that are
+//! finite but degenerate (such as an `Xyz` that divides by zero on its way to
+//! `Luv`).
+
+use crate::{Hpluv, Hsluv, Lch, Luv, Rgb, Xyz};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum NonFinite {
+    Nan,
+    Infinite,
+}
+
+pub trait Finite {
+    fn check_finite(&self) -> Result<(), NonFinite>;
+
+    fn is_finite(&self) -> bool {
+        self.check_finite().is_ok()
+    }
+}
+
+fn check_components((a, b, c): (f64, f64, f64)) -> Result<(), NonFinite> {
+    if a.is_nan() || b.is_nan() || c.is_nan() {
+        Err(NonFinite::Nan)
+    } else if a.is_infinite() || b.is_infinite() || c.is_infinite() {
+        Err(NonFinite::Infinite)
+    } else {
+        Ok(())
+    }
+}
+
+impl Finite for Rgb {
+    fn check_finite(&self) -> Result<(), NonFinite> {
+        check_components(self.rgb())
+    }
+}
+impl Finite for Xyz {
+    fn check_finite(&self) -> Result<(), NonFinite> {
+        check_components(self.xyz())
+    }
+}
+impl Finite for Luv {
+    fn check_finite(&self) -> Result<(), NonFinite> {
+        check_components(self.luv())
+    }
+}
+impl Finite for Lch {
+    fn check_finite(&self) -> Result<(), NonFinite> {
+        check_components(self.lch())
+    }
+}
+impl Finite for Hsluv {
+    fn check_finite(&self) -> Result<(), NonFinite> {
+        check_components(self.hsl())
+    }
+}
+impl Finite for Hpluv {
+    fn check_finite(&self) -> Result<(), NonFinite> {
+        check_components(self.hsl())
+    }
+}
+
+pub trait CheckedFrom<T>: Sized {
+    fn checked_from(value: T) -> Result<Self, NonFinite>;
+}
+
+impl<T: Finite, U: From<T> + Finite> CheckedFrom<T> for U {
+    fn checked_from(value: T) -> Result<U, NonFinite> {
+        value.check_finite()?;
+        let converted = U::from(value);
+        converted.check_finite()?;
+        Ok(converted)
+    }
+}
+
+pub trait CheckedInto<U> {
+    fn checked_into(self) -> Result<U, NonFinite>;
+}
+
+impl<T, U: CheckedFrom<T>> CheckedInto<U> for T {
+    fn checked_into(self) -> Result<U, NonFinite> {
+        U::checked_from(self)
+    }
+}
@@ -0,0 +1,142 @@
+//! Comparing colors by what they look like rather than by their fields.
+//!
+//! The derived `PartialEq` compares raw components, so hue 0 and hue 360
+//! differ, as do grays with different hues. `ApproxEq` treats those as the
+//! same color. `HsluvKey` quantizes an `Hsluv` into a canonical form that
+//! can be hashed and ordered, for deduplicating colors in a `HashMap` or
+//! `BTreeMap`. To deduplicate by what renders on an 8-bit display instead,
+//! `Rgb::to_rgb8` already gives a hashable key.
+
+use crate::{wrap_hue, Hpluv, Hsluv, Lch, Luv, Rgb, Xyz};
+use num_traits::float::Float;
+
+pub trait ApproxEq {
+    /// Whether each component is within `epsilon` of `other`'s, in the
+    /// component's own units. Hues compare around the circle, and are
+    /// ignored for grays; for black and white, only lightness counts.
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool;
+}
+
+fn close(a: f64, b: f64, epsilon: f64) -> bool {
+    Float::abs(a - b) <= epsilon
+}
+
+fn hues_close(a: f64, b: f64, epsilon: f64) -> bool {
+    let difference = wrap_hue(a - b);
+    difference.min(360.0 - difference) <= epsilon
+}
+
+fn triples_close(
+    (a0, a1, a2): (f64, f64, f64),
+    (b0, b1, b2): (f64, f64, f64),
+    epsilon: f64,
+) -> bool {
+    close(a0, b0, epsilon) && close(a1, b1, epsilon) && close(a2, b2, epsilon)
+}
+
+impl ApproxEq for Rgb {
+    fn approx_eq(&self, other: &Rgb, epsilon: f64) -> bool {
+        triples_close(self.rgb(), other.rgb(), epsilon)
+    }
+}
+
+impl ApproxEq for Xyz {
+    fn approx_eq(&self, other: &Xyz, epsilon: f64) -> bool {
+        triples_close(self.xyz(), other.xyz(), epsilon)
+    }
+}
+
+impl ApproxEq for Luv {
+    fn approx_eq(&self, other: &Luv, epsilon: f64) -> bool {
+        triples_close(self.luv(), other.luv(), epsilon)
+    }
+}
+
+impl ApproxEq for Lch {
+    fn approx_eq(&self, other: &Lch, epsilon: f64) -> bool {
+        close(self.lightness, other.lightness, epsilon)
+            && close(self.chroma, other.chroma, epsilon)
+            && (self.chroma.max(other.chroma) <= epsilon
+                || hues_close(self.hue, other.hue, epsilon))
+    }
+}
+
+// `(hue, saturation, lightness)`, for both HSLuv and HPLuv.
+fn hsl_approx_eq(
+    (hue, saturation, lightness): (f64, f64, f64),
+    (other_hue, other_saturation, other_lightness): (f64, f64, f64),
+    epsilon: f64,
+) -> bool {
+    if !close(lightness, other_lightness, epsilon) {
+        return false;
+    }
+    // Black and white
+    if lightness.max(other_lightness) <= epsilon
+        || lightness.min(other_lightness) >= 100.0 - epsilon
+    {
+        return true;
+    }
+    close(saturation, other_saturation, epsilon)
+        && (saturation.max(other_saturation) <= epsilon || hues_close(hue, other_hue, epsilon))
+}
+
+impl ApproxEq for Hsluv {
+    fn approx_eq(&self, other: &Hsluv, epsilon: f64) -> bool {
+        hsl_approx_eq(self.hsl(), other.hsl(), epsilon)
+    }
+}
+
+impl ApproxEq for Hpluv {
+    fn approx_eq(&self, other: &Hpluv, epsilon: f64) -> bool {
+        hsl_approx_eq(self.hsl(), other.hsl(), epsilon)
+    }
+}
+
+/// An `Hsluv` rounded to the fixed-point scale of `hsluv_to_rgb8_fixed`:
+/// `hue` covers 0..360 with 65536 wrapping back to 0, and `saturation` and
+/// `lightness` cover 0..=100 with `u16::MAX` as 100, about 0.0015 per step.
+///
+/// Keys are canonical, so colors that only differ in meaningless components
+/// share one: grays have hue 0, and black and white have hue and
+/// saturation 0. Out-of-range saturation and lightness are clamped.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HsluvKey {
+    pub hue: u16,
+    pub saturation: u16,
+    pub lightness: u16,
+}
+
+impl HsluvKey {
+    fn quantize(component: f64) -> u16 {
+        Float::round(component.clamp(0.0, 100.0) / 100.0 * 65535.0) as u16
+    }
+}
+
+impl Hsluv {
+    pub fn key(&self) -> HsluvKey {
+        let lightness = HsluvKey::quantize(self.lightness);
+        let saturation = match lightness {
+            0 | u16::MAX => 0,
+            _ => HsluvKey::quantize(self.saturation),
+        };
+        let hue = match saturation {
+            0 => 0,
+            _ => (Float::round(wrap_hue(self.hue) / 360.0 * 65536.0) as u32 % 65536) as u16,
+        };
+        HsluvKey {
+            hue,
+            saturation,
+            lightness,
+        }
+    }
+}
+
+impl From<HsluvKey> for Hsluv {
+    fn from(key: HsluvKey) -> Hsluv {
+        Hsluv {
+            hue: f64::from(key.hue) / 65536.0 * 360.0,
+            saturation: f64::from(key.saturation) / 65535.0 * 100.0,
+            lightness: f64::from(key.lightness) / 65535.0 * 100.0,
+        }
+    }
+}
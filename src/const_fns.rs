@@ -0,0 +1,261 @@
+//! `const fn` conversions for building color tables at compile time.
+//!
+//! `const` contexts can't call `powf`, `cbrt` or the trigonometric functions,
+//! so this module carries its own Newton's method roots and Taylor series, and
+//! otherwise shares the `const fn` steps of the `From` impls. Results agree
+//! with those impls to within 1e-10, but aren't bit-identical to them.
+
+use crate::{
+    closest_bound, get_bounds, hsl_to_lch, lch_to_hsl, lch_to_luv, linear_rgb_to_xyz, luv_to_lch,
+    luv_to_xyz, max_chroma_for_bounds_sin_cos, xyz_to_linear_rgb, xyz_to_luv, Hpluv, Hsluv, Lch,
+    Line, Rgb, Xyz, EPSILON, KAPPA,
+};
+use core::f64::consts::PI;
+
+// pi / 2 as the nearest f64 plus what that rounding dropped
+const FRAC_PI_2_HI: f64 = core::f64::consts::FRAC_PI_2;
+const FRAC_PI_2_LO: f64 = 6.12323399573676603587e-17;
+
+// `n`th root of a non-negative `x`, by Newton's method from an exponent-halving
+// (thirding, ...) guess. Converges from above, so stop once it stops falling.
+pub(crate) const fn root(x: f64, n: u32) -> f64 {
+    if x.is_nan() || x <= 0.0 || x == f64::INFINITY {
+        return x;
+    }
+    let guess_bias = (1023 << 52) / n as u64 * (n as u64 - 1);
+    let mut y = f64::from_bits(x.to_bits() / n as u64 + guess_bias);
+    let mut i = 0;
+    while i < 200 {
+        let mut y_pow = 1.0;
+        let mut j = 1;
+        while j < n {
+            y_pow *= y;
+            j += 1;
+        }
+        let next = ((n - 1) as f64 * y + x / y_pow) / n as f64;
+        if i > 0 && next >= y {
+            break;
+        }
+        y = next;
+        i += 1;
+    }
+    y
+}
+
+const fn sqrt(x: f64) -> f64 {
+    root(x, 2)
+}
+
+const fn cbrt(x: f64) -> f64 {
+    if x < 0.0 {
+        -root(-x, 3)
+    } else {
+        root(x, 3)
+    }
+}
+
+// Returns (sin(x), cos(x)), reducing x to within pi / 4 of a multiple of
+// pi / 2 first.
+pub(crate) const fn sin_cos(x: f64) -> (f64, f64) {
+    let quadrants = x / FRAC_PI_2_HI;
+    let k = if quadrants < 0.0 {
+        (quadrants - 0.5) as i64
+    } else {
+        (quadrants + 0.5) as i64
+    };
+    let r = (x - k as f64 * FRAC_PI_2_HI) - k as f64 * FRAC_PI_2_LO;
+    let r2 = r * r;
+
+    // Taylor series; terms past r^17 are below 1e-19 for |r| <= pi / 4.
+    let mut sin = 0.0;
+    let mut cos = 0.0;
+    let mut term_sin = r;
+    let mut term_cos = 1.0;
+    let mut i = 1;
+    while i <= 9 {
+        sin += term_sin;
+        cos += term_cos;
+        term_sin *= -r2 / ((2 * i) as f64 * (2 * i + 1) as f64);
+        term_cos *= -r2 / ((2 * i - 1) as f64 * (2 * i) as f64);
+        i += 1;
+    }
+
+    match k.rem_euclid(4) {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+const fn atan(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    let (negative, x) = if x < 0.0 { (true, -x) } else { (false, x) };
+    let (inverted, x) = if x > 1.0 { (true, 1.0 / x) } else { (false, x) };
+    // Halve the angle twice, leaving x <= tan(pi / 16) < 0.2.
+    let x = x / (1.0 + sqrt(1.0 + x * x));
+    let x = x / (1.0 + sqrt(1.0 + x * x));
+
+    let x2 = x * x;
+    let mut sum = 0.0;
+    let mut power = x;
+    let mut i = 0;
+    while i < 14 {
+        let term = power / (2 * i + 1) as f64;
+        sum = if i % 2 == 0 { sum + term } else { sum - term };
+        power *= x2;
+        i += 1;
+    }
+
+    let angle = 4.0 * sum;
+    let angle = if inverted { PI / 2.0 - angle } else { angle };
+    if negative {
+        -angle
+    } else {
+        angle
+    }
+}
+
+const fn atan2(y: f64, x: f64) -> f64 {
+    if x > 0.0 {
+        atan(y / x)
+    } else if x < 0.0 {
+        if y < 0.0 {
+            atan(y / x) - PI
+        } else {
+            atan(y / x) + PI
+        }
+    } else if y > 0.0 {
+        PI / 2.0
+    } else if y < 0.0 {
+        -PI / 2.0
+    } else {
+        0.0
+    }
+}
+
+const fn from_linear(c: f64) -> f64 {
+    if c <= 0.0031308 {
+        12.92 * c
+    } else {
+        // c^(1 / 2.4) = (c^(1 / 12))^5
+        let r = root(c, 12);
+        1.055 * (r * r * r * r * r) - 0.055
+    }
+}
+
+pub(crate) const fn to_linear(c: f64) -> f64 {
+    if c > 0.04045 {
+        // x^2.4 = x^2 * (x^(1 / 5))^2
+        let x = (c + 0.055) / 1.055;
+        let r = root(x, 5);
+        x * x * r * r
+    } else {
+        c / 12.92
+    }
+}
+
+const fn y_to_lightness(y: f64) -> f64 {
+    if y <= EPSILON {
+        y * KAPPA
+    } else {
+        116.0 * cbrt(y) - 16.0
+    }
+}
+
+const fn xyz_to_rgb(xyz: Xyz) -> Rgb {
+    let (red, green, blue) = xyz_to_linear_rgb(xyz);
+    Rgb {
+        red: from_linear(red),
+        green: from_linear(green),
+        blue: from_linear(blue),
+    }
+}
+
+const fn rgb_to_xyz(rgb: Rgb) -> Xyz {
+    linear_rgb_to_xyz((
+        to_linear(rgb.red),
+        to_linear(rgb.green),
+        to_linear(rgb.blue),
+    ))
+}
+
+const fn max_safe_chroma(bounds: &[Line; 6]) -> f64 {
+    let line = closest_bound(bounds);
+    let intercept = if line.intercept < 0.0 {
+        -line.intercept
+    } else {
+        line.intercept
+    };
+    intercept / sqrt(line.slope * line.slope + 1.0)
+}
+
+const fn max_chroma(bounds: &[Line; 6], hue: f64) -> f64 {
+    // (pi / 180)
+    let (sin, cos) = sin_cos(hue * 0.01745329251994329577);
+    max_chroma_for_bounds_sin_cos(bounds, sin, cos)
+}
+
+const fn rgb_to_lch(rgb: Rgb) -> Lch {
+    let xyz = rgb_to_xyz(rgb);
+    let luv = xyz_to_luv(xyz, y_to_lightness(xyz.y));
+    let chroma = sqrt(luv.u * luv.u + luv.v * luv.v);
+    // (180 / pi)
+    luv_to_lch(luv, chroma, atan2(luv.v, luv.u) * 57.29577951308232087680)
+}
+
+const fn lch_to_rgb(lch: Lch) -> Rgb {
+    // (pi / 180)
+    let (sin, cos) = sin_cos(lch.hue * 0.01745329251994329577);
+    xyz_to_rgb(luv_to_xyz(lch_to_luv(lch, sin, cos)))
+}
+
+impl Rgb {
+    pub const fn from_hsluv_const(hsluv: Hsluv) -> Rgb {
+        let max_chroma = max_chroma(&get_bounds(hsluv.lightness), hsluv.hue);
+        lch_to_rgb(hsl_to_lch(
+            hsluv.hue,
+            hsluv.saturation,
+            hsluv.lightness,
+            max_chroma,
+        ))
+    }
+
+    pub const fn from_hpluv_const(hpluv: Hpluv) -> Rgb {
+        let max_chroma = max_safe_chroma(&get_bounds(hpluv.lightness));
+        lch_to_rgb(hsl_to_lch(
+            hpluv.hue,
+            hpluv.saturation,
+            hpluv.lightness,
+            max_chroma,
+        ))
+    }
+}
+
+impl Hsluv {
+    pub const fn from_rgb_const(rgb: Rgb) -> Hsluv {
+        let lch = rgb_to_lch(rgb);
+        let max_chroma = max_chroma(&get_bounds(lch.lightness), lch.hue);
+        let (hue, saturation, lightness) = lch_to_hsl(lch, max_chroma);
+        Hsluv {
+            hue,
+            saturation,
+            lightness,
+        }
+    }
+}
+
+impl Hpluv {
+    pub const fn from_rgb_const(rgb: Rgb) -> Hpluv {
+        let lch = rgb_to_lch(rgb);
+        let max_chroma = max_safe_chroma(&get_bounds(lch.lightness));
+        let (hue, saturation, lightness) = lch_to_hsl(lch, max_chroma);
+        Hpluv {
+            hue,
+            saturation,
+            lightness,
+        }
+    }
+}
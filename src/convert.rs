@@ -0,0 +1,274 @@
+//! Conversion between any two color spaces.
+//!
+//! The `From` impls only connect neighbouring spaces, which form a chain
+//! `Rgb - Xyz - Luv - Lch` with `Hsluv` and `Hpluv` both hanging off `Lch`.
+//! `FromColor` walks that chain, so `Hpluv` reaches `Luv` through `Lch`,
+//! `Hsluv` reaches `Hpluv` through `Lch`, and `Hsluv` reaches `Rgb` through
+//! `Lch`, `Luv` and `Xyz`: the same steps as the `From` impls that already
+//! exist. Converting to the same space returns the color unchanged.
+
+use crate::{Hpluv, Hsluv, Lch, Luv, Rgb, Xyz};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Space {
+    Rgb,
+    Xyz,
+    Luv,
+    Lch,
+    Hsluv,
+    Hpluv,
+}
+
+/// A color in any space, for choosing the space at runtime.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub enum AnyColor {
+    Rgb(Rgb),
+    Xyz(Xyz),
+    Luv(Luv),
+    Lch(Lch),
+    Hsluv(Hsluv),
+    Hpluv(Hpluv),
+}
+
+impl AnyColor {
+    pub fn space(&self) -> Space {
+        match self {
+            AnyColor::Rgb(_) => Space::Rgb,
+            AnyColor::Xyz(_) => Space::Xyz,
+            AnyColor::Luv(_) => Space::Luv,
+            AnyColor::Lch(_) => Space::Lch,
+            AnyColor::Hsluv(_) => Space::Hsluv,
+            AnyColor::Hpluv(_) => Space::Hpluv,
+        }
+    }
+
+    pub fn convert(self, space: Space) -> AnyColor {
+        let mut color = self;
+        while color.space() != space {
+            color = color.step(space);
+        }
+        color
+    }
+
+    // One edge along the chain towards `target`, which must be another space.
+    fn step(self, target: Space) -> AnyColor {
+        match self {
+            AnyColor::Rgb(rgb) => AnyColor::Xyz(rgb.into()),
+            AnyColor::Xyz(xyz) => match target {
+                Space::Rgb => AnyColor::Rgb(xyz.into()),
+                _ => AnyColor::Luv(xyz.into()),
+            },
+            AnyColor::Luv(luv) => match target {
+                Space::Rgb | Space::Xyz => AnyColor::Xyz(luv.into()),
+                _ => AnyColor::Lch(luv.into()),
+            },
+            AnyColor::Lch(lch) => match target {
+                Space::Hsluv => AnyColor::Hsluv(lch.into()),
+                Space::Hpluv => AnyColor::Hpluv(lch.into()),
+                _ => AnyColor::Luv(lch.into()),
+            },
+            AnyColor::Hsluv(hsluv) => AnyColor::Lch(hsluv.into()),
+            AnyColor::Hpluv(hpluv) => AnyColor::Lch(hpluv.into()),
+        }
+    }
+}
+
+/// A color space on the chain, for converting generically with `FromColor`.
+///
+/// Only `into_luv`, `from_luv` and the component methods are needed; the
+/// rest step through `Luv`, and are overridden where a shorter route exists.
+pub trait ColorSpace: Copy {
+    const SPACE: Space;
+
+    fn into_any(self) -> AnyColor;
+
+    /// `None` unless `color` is in `Self::SPACE`.
+    fn from_any(color: AnyColor) -> Option<Self>;
+
+    fn components(self) -> (f64, f64, f64);
+
+    fn from_components(components: (f64, f64, f64)) -> Self;
+
+    fn into_luv(self) -> Luv;
+
+    fn from_luv(luv: Luv) -> Self;
+
+    fn into_xyz(self) -> Xyz {
+        Xyz::from(self.into_luv())
+    }
+
+    fn from_xyz(xyz: Xyz) -> Self {
+        Self::from_luv(Luv::from(xyz))
+    }
+
+    fn into_lch(self) -> Lch {
+        Lch::from(self.into_luv())
+    }
+
+    fn from_lch(lch: Lch) -> Self {
+        Self::from_luv(Luv::from(lch))
+    }
+}
+
+macro_rules! impl_color_space {
+    ($t:ident, $components:ident, $a:ident, $b:ident, $c:ident, { $($route:tt)* }) => {
+        impl ColorSpace for $t {
+            const SPACE: Space = Space::$t;
+
+            fn into_any(self) -> AnyColor {
+                AnyColor::$t(self)
+            }
+
+            fn from_any(color: AnyColor) -> Option<$t> {
+                match color {
+                    AnyColor::$t(color) => Some(color),
+                    _ => None,
+                }
+            }
+
+            fn components(self) -> (f64, f64, f64) {
+                self.$components()
+            }
+
+            fn from_components(($a, $b, $c): (f64, f64, f64)) -> $t {
+                $t { $a, $b, $c }
+            }
+
+            $($route)*
+        }
+
+        impl From<$t> for AnyColor {
+            fn from(color: $t) -> AnyColor {
+                AnyColor::$t(color)
+            }
+        }
+    };
+}
+
+impl_color_space!(Rgb, rgb, red, green, blue, {
+    fn into_luv(self) -> Luv {
+        Luv::from(Xyz::from(self))
+    }
+
+    fn from_luv(luv: Luv) -> Rgb {
+        Rgb::from(Xyz::from(luv))
+    }
+
+    fn into_xyz(self) -> Xyz {
+        Xyz::from(self)
+    }
+
+    fn from_xyz(xyz: Xyz) -> Rgb {
+        Rgb::from(xyz)
+    }
+});
+impl_color_space!(Xyz, xyz, x, y, z, {
+    fn into_luv(self) -> Luv {
+        Luv::from(self)
+    }
+
+    fn from_luv(luv: Luv) -> Xyz {
+        Xyz::from(luv)
+    }
+
+    fn into_xyz(self) -> Xyz {
+        self
+    }
+
+    fn from_xyz(xyz: Xyz) -> Xyz {
+        xyz
+    }
+});
+impl_color_space!(Luv, luv, lightness, u, v, {
+    fn into_luv(self) -> Luv {
+        self
+    }
+
+    fn from_luv(luv: Luv) -> Luv {
+        luv
+    }
+});
+impl_color_space!(Lch, lch, lightness, chroma, hue, {
+    fn into_luv(self) -> Luv {
+        Luv::from(self)
+    }
+
+    fn from_luv(luv: Luv) -> Lch {
+        Lch::from(luv)
+    }
+
+    fn into_lch(self) -> Lch {
+        self
+    }
+
+    fn from_lch(lch: Lch) -> Lch {
+        lch
+    }
+});
+impl_color_space!(Hsluv, hsl, hue, saturation, lightness, {
+    fn into_luv(self) -> Luv {
+        Luv::from(Lch::from(self))
+    }
+
+    fn from_luv(luv: Luv) -> Hsluv {
+        Hsluv::from(Lch::from(luv))
+    }
+
+    fn into_lch(self) -> Lch {
+        Lch::from(self)
+    }
+
+    fn from_lch(lch: Lch) -> Hsluv {
+        Hsluv::from(lch)
+    }
+});
+impl_color_space!(Hpluv, hsl, hue, saturation, lightness, {
+    fn into_luv(self) -> Luv {
+        Luv::from(Lch::from(self))
+    }
+
+    fn from_luv(luv: Luv) -> Hpluv {
+        Hpluv::from(Lch::from(luv))
+    }
+
+    fn into_lch(self) -> Lch {
+        Lch::from(self)
+    }
+
+    fn from_lch(lch: Lch) -> Hpluv {
+        Hpluv::from(lch)
+    }
+});
+
+pub trait FromColor<T> {
+    fn from_color(color: T) -> Self;
+}
+
+// The route only depends on the two spaces, so each branch is decided at
+// compile time: colors meet at `Xyz` when both are on its side of `Luv`, at
+// `Lch` when both are on its side, and at `Luv` otherwise.
+impl<T: ColorSpace, U: ColorSpace> FromColor<T> for U {
+    fn from_color(color: T) -> U {
+        let xyz_side = |space| matches!(space, Space::Rgb | Space::Xyz);
+        let lch_side = |space| matches!(space, Space::Lch | Space::Hsluv | Space::Hpluv);
+        if T::SPACE == U::SPACE {
+            U::from_components(color.components())
+        } else if xyz_side(T::SPACE) && xyz_side(U::SPACE) {
+            U::from_xyz(color.into_xyz())
+        } else if lch_side(T::SPACE) && lch_side(U::SPACE) {
+            U::from_lch(color.into_lch())
+        } else {
+            U::from_luv(color.into_luv())
+        }
+    }
+}
+
+pub trait IntoColor<U> {
+    fn into_color(self) -> U;
+}
+
+impl<T: ColorSpace, U: FromColor<T>> IntoColor<U> for T {
+    fn into_color(self) -> U {
+        U::from_color(self)
+    }
+}
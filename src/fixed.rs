@@ -0,0 +1,186 @@
+//! Integer-only HSLuv to 8-bit sRGB, for targets without an FPU.
+//!
+//! The sRGB transfer function, the lightness-to-Y curve and the sine are
+//! lookup tables built at compile time by the `const_fns` math; everything
+//! at runtime is `i64` arithmetic. Over every color of the snapshot, the
+//! result is at most one 8-bit step away from rounding the `f64` path's
+//! output for the same quantized input.
+
+use crate::const_fns::{sin_cos, to_linear};
+use crate::{lightness_to_y, M, REF_U, REF_V};
+
+// Y, u', v', XYZ and linear RGB are Q24; sines are Q14; lightness is Q8 and
+// chroma Q16.
+const Q24: f64 = (1u32 << 24) as f64;
+const Q20: f64 = (1u32 << 20) as f64;
+
+const fn round(x: f64) -> i64 {
+    if x < 0.0 {
+        (x - 0.5) as i64
+    } else {
+        (x + 0.5) as i64
+    }
+}
+
+// Y at lightness i / 256 of full scale, so the top 8 bits of a `u16`
+// lightness index it and the low 8 bits interpolate.
+const LIGHTNESS_TO_Y: [i32; 257] = {
+    let mut table = [0; 257];
+    let mut i = 0;
+    while i < table.len() {
+        let lightness = (i * 256) as f64 * 100.0 / 65535.0;
+        table[i] = round(lightness_to_y(lightness) * Q24) as i32;
+        i += 1;
+    }
+    table
+};
+
+// Sine over a quarter turn, in Q14.
+const QUARTER_SINE: [i16; 257] = {
+    let mut table = [0; 257];
+    let mut i = 0;
+    while i < table.len() {
+        let (sin, _) = sin_cos(i as f64 * core::f64::consts::FRAC_PI_2 / 256.0);
+        table[i] = round(sin * 16384.0) as i16;
+        i += 1;
+    }
+    table
+};
+
+// Linear light halfway between consecutive 8-bit sRGB codes. The number of
+// thresholds at or below a linear value is its rounded code.
+const SRGB_THRESHOLDS: [i32; 255] = {
+    let mut table = [0; 255];
+    let mut i = 0;
+    while i < table.len() {
+        table[i] = round(to_linear((i as f64 + 0.5) / 255.0) * Q24) as i32;
+        i += 1;
+    }
+    table
+};
+
+// Per channel, the slope numerator and denominator coefficients of
+// `get_bounds`. Its intercept coefficient is 769860 for every channel.
+const BOUND_COEFFICIENTS: [(i64, i64); 3] = {
+    let mut coefficients = [(0, 0); 3];
+    let mut i = 0;
+    while i < coefficients.len() {
+        let m = M[i];
+        coefficients[i] = (
+            round(284517.0 * m.0 - 94839.0 * m.2),
+            round(632260.0 * m.2 - 126452.0 * m.1),
+        );
+        i += 1;
+    }
+    coefficients
+};
+
+const M_Q20: [(i64, i64, i64); 3] = {
+    let mut m = [(0, 0, 0); 3];
+    let mut i = 0;
+    while i < m.len() {
+        m[i] = (
+            round(M[i].0 * Q20),
+            round(M[i].1 * Q20),
+            round(M[i].2 * Q20),
+        );
+        i += 1;
+    }
+    m
+};
+
+const REF_U_Q24: i64 = round(REF_U * Q24);
+const REF_V_Q24: i64 = round(REF_V * Q24);
+
+fn interpolate(a: i64, b: i64, fraction: i64, bits: u32) -> i64 {
+    a + (((b - a) * fraction + (1 << (bits - 1))) >> bits)
+}
+
+fn y_for_lightness(lightness: u16) -> i64 {
+    let i = usize::from(lightness >> 8);
+    interpolate(
+        i64::from(LIGHTNESS_TO_Y[i]),
+        i64::from(LIGHTNESS_TO_Y[i + 1]),
+        i64::from(lightness & 0xff),
+        8,
+    )
+}
+
+// Returns (sin, cos) in Q14 of a hue where 65536 is a full turn.
+fn hue_sin_cos(hue: u16) -> (i64, i64) {
+    fn sine(offset: u16) -> i64 {
+        let i = usize::from(offset >> 6);
+        if i + 1 >= QUARTER_SINE.len() {
+            return i64::from(QUARTER_SINE[QUARTER_SINE.len() - 1]);
+        }
+        interpolate(
+            i64::from(QUARTER_SINE[i]),
+            i64::from(QUARTER_SINE[i + 1]),
+            i64::from(offset & 0x3f),
+            6,
+        )
+    }
+
+    let offset = hue & 0x3fff;
+    let (sin, cos) = (sine(offset), sine(0x4000 - offset));
+    match hue >> 14 {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+fn encode(linear: i64) -> u8 {
+    SRGB_THRESHOLDS.partition_point(|&threshold| i64::from(threshold) <= linear) as u8
+}
+
+/// Converts HSLuv to 8-bit sRGB without floating point. Each component is a
+/// fraction of full scale: `hue` covers 0..360 with 65536 wrapping back to 0,
+/// and `saturation` and `lightness` cover 0..=100 with `u16::MAX` as 100.
+pub fn hsluv_to_rgb8_fixed(hue: u16, saturation: u16, lightness: u16) -> [u8; 3] {
+    match lightness {
+        0 => return [0, 0, 0],
+        u16::MAX => return [255, 255, 255],
+        _ => {}
+    }
+    let y = y_for_lightness(lightness);
+    let l = (i64::from(lightness) * 25600 + 32767) / 65535;
+    if l == 0 {
+        // Too dark to tell from black, and a divide-by-zero below.
+        return [0, 0, 0];
+    }
+    let (sin, cos) = hue_sin_cos(hue);
+
+    // The same ray-line intersections as `max_chroma_for_lightness_hue`,
+    // with the slope and intercept divisions folded into one.
+    let mut max_chroma = i64::MAX;
+    for &(slope_top, bottom_y) in &BOUND_COEFFICIENTS {
+        for t in 0..2 {
+            let top1 = slope_top * y;
+            let bottom = bottom_y * y + 126452 * (t << 24);
+            let top2 = 769860 * l * (y - (t << 24));
+            let denominator = (bottom * sin - top1 * cos) >> 22;
+            if denominator != 0 {
+                let length = top2 / denominator;
+                if length >= 0 && length < max_chroma {
+                    max_chroma = length;
+                }
+            }
+        }
+    }
+    if max_chroma == i64::MAX {
+        max_chroma = 0;
+    }
+    let chroma = max_chroma * i64::from(saturation) / 65535;
+
+    let u = (chroma * cos) >> 14;
+    let v = (chroma * sin) >> 14;
+    let var_u = (u << 16) / (13 * l) + REF_U_Q24;
+    let var_v = ((v << 16) / (13 * l) + REF_V_Q24).max(1);
+    let x = 9 * y * var_u / (4 * var_v);
+    let z = y * ((12 << 24) - 3 * var_u - 20 * var_v) / (4 * var_v);
+
+    let channel = |m: (i64, i64, i64)| encode((m.0 * x + m.1 * y + m.2 * z + (1 << 19)) >> 20);
+    [channel(M_Q20[0]), channel(M_Q20[1]), channel(M_Q20[2])]
+}
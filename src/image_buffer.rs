@@ -0,0 +1,210 @@
+//! Whole-image conversions and adjustments for [`image`] buffers.
+//!
+//! Pixels are read as sRGB with channels scaled by their type's
+//! `DEFAULT_MAX_VALUE`. Alpha is carried through untouched.
+
+use crate::{hsluv_to_rgb_slice, rgb_to_hsluv_slice, Hsluv, Rgb};
+use ::image::{ImageBuffer, Pixel, Primitive};
+use core::ops::{Deref, DerefMut};
+use num_traits::float::Float;
+
+// Pixels converted per batch call in `map_hsluv`, bounding stack use.
+const CHUNK: usize = 256;
+
+fn to_unit<S: Primitive>(c: S) -> f64 {
+    c.to_f64().unwrap_or(0.0) / S::DEFAULT_MAX_VALUE.to_f64().unwrap_or(1.0)
+}
+
+fn from_unit<S: Primitive>(c: f64) -> S {
+    let max = S::DEFAULT_MAX_VALUE.to_f64().unwrap_or(1.0);
+    let scaled = Float::min(Float::max(c, 0.0), 1.0) * max;
+    // Integer channels have a maximum above 1 and need rounding; float
+    // channels keep their full precision.
+    let scaled = if max > 1.0 {
+        Float::round(scaled)
+    } else {
+        scaled
+    };
+    S::from(scaled).unwrap_or(S::DEFAULT_MAX_VALUE)
+}
+
+/// Pixel types whose color channels are sRGB red, green and blue.
+pub trait RgbPixel: Pixel {
+    fn to_color(&self) -> Rgb;
+    fn set_color(&mut self, rgb: Rgb);
+    /// Alpha scaled to 0..=1, or 1 for opaque pixel types.
+    fn alpha(&self) -> f64;
+    fn from_color(rgb: Rgb, alpha: f64) -> Self;
+}
+
+impl<S: Primitive> RgbPixel for ::image::Rgb<S>
+where
+    ::image::Rgb<S>: Pixel<Subpixel = S>,
+{
+    fn to_color(&self) -> Rgb {
+        let [red, green, blue] = self.0;
+        Rgb {
+            red: to_unit(red),
+            green: to_unit(green),
+            blue: to_unit(blue),
+        }
+    }
+
+    fn set_color(&mut self, rgb: Rgb) {
+        self.0 = [
+            from_unit(rgb.red),
+            from_unit(rgb.green),
+            from_unit(rgb.blue),
+        ];
+    }
+
+    fn alpha(&self) -> f64 {
+        1.0
+    }
+
+    fn from_color(rgb: Rgb, _alpha: f64) -> Self {
+        ::image::Rgb([
+            from_unit(rgb.red),
+            from_unit(rgb.green),
+            from_unit(rgb.blue),
+        ])
+    }
+}
+
+impl<S: Primitive> RgbPixel for ::image::Rgba<S>
+where
+    ::image::Rgba<S>: Pixel<Subpixel = S>,
+{
+    fn to_color(&self) -> Rgb {
+        let [red, green, blue, _] = self.0;
+        Rgb {
+            red: to_unit(red),
+            green: to_unit(green),
+            blue: to_unit(blue),
+        }
+    }
+
+    fn set_color(&mut self, rgb: Rgb) {
+        let alpha = self.0[3];
+        self.0 = [
+            from_unit(rgb.red),
+            from_unit(rgb.green),
+            from_unit(rgb.blue),
+            alpha,
+        ];
+    }
+
+    fn alpha(&self) -> f64 {
+        to_unit(self.0[3])
+    }
+
+    fn from_color(rgb: Rgb, alpha: f64) -> Self {
+        ::image::Rgba([
+            from_unit(rgb.red),
+            from_unit(rgb.green),
+            from_unit(rgb.blue),
+            from_unit(alpha),
+        ])
+    }
+}
+
+/// An image as row-major HSLuv pixels with a parallel alpha plane.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HsluvImage {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<Hsluv>,
+    pub alpha: Vec<f64>,
+}
+
+impl HsluvImage {
+    pub fn from_image<P, C>(image: &ImageBuffer<P, C>) -> HsluvImage
+    where
+        P: RgbPixel,
+        C: Deref<Target = [P::Subpixel]>,
+    {
+        let rgb: Vec<Rgb> = image.pixels().map(RgbPixel::to_color).collect();
+        let mut pixels = vec![
+            Hsluv {
+                hue: 0.0,
+                saturation: 0.0,
+                lightness: 0.0
+            };
+            rgb.len()
+        ];
+        rgb_to_hsluv_slice(&rgb, &mut pixels);
+        HsluvImage {
+            width: image.width(),
+            height: image.height(),
+            pixels,
+            alpha: image.pixels().map(RgbPixel::alpha).collect(),
+        }
+    }
+
+    /// Converts back to an image, clamping out-of-gamut colors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pixels` or `alpha` don't hold `width * height` entries.
+    pub fn to_image<P: RgbPixel>(&self) -> ImageBuffer<P, Vec<P::Subpixel>> {
+        let len = self.width as usize * self.height as usize;
+        assert_eq!(self.pixels.len(), len, "HSLuv plane size doesn't match");
+        assert_eq!(self.alpha.len(), len, "alpha plane size doesn't match");
+        let mut rgb = vec![
+            Rgb {
+                red: 0.0,
+                green: 0.0,
+                blue: 0.0
+            };
+            len
+        ];
+        hsluv_to_rgb_slice(&self.pixels, &mut rgb);
+        let mut image = ImageBuffer::new(self.width, self.height);
+        for ((pixel, rgb), alpha) in image.pixels_mut().zip(rgb).zip(&self.alpha) {
+            *pixel = P::from_color(rgb, *alpha);
+        }
+        image
+    }
+
+    pub fn map(&mut self, mut f: impl FnMut(Hsluv) -> Hsluv) {
+        for pixel in &mut self.pixels {
+            *pixel = f(*pixel);
+        }
+    }
+}
+
+/// Replaces every pixel's color with `f` applied to it in HSLuv, clamping
+/// out-of-gamut results. Alpha is left as is.
+pub fn map_hsluv<P, C>(image: &mut ImageBuffer<P, C>, mut f: impl FnMut(Hsluv) -> Hsluv)
+where
+    P: RgbPixel,
+    C: Deref<Target = [P::Subpixel]> + DerefMut,
+{
+    let mut rgb = [Rgb {
+        red: 0.0,
+        green: 0.0,
+        blue: 0.0,
+    }; CHUNK];
+    let mut hsluv = [Hsluv {
+        hue: 0.0,
+        saturation: 0.0,
+        lightness: 0.0,
+    }; CHUNK];
+    let channels = usize::from(P::CHANNEL_COUNT);
+    // The container may run past the last pixel; leave those samples alone.
+    let samples = image.width() as usize * image.height() as usize * channels;
+    for chunk in image.deref_mut()[..samples].chunks_mut(channels * CHUNK) {
+        let len = chunk.len() / channels;
+        for (pixel, rgb) in chunk.chunks_exact(channels).zip(&mut rgb) {
+            *rgb = P::from_slice(pixel).to_color();
+        }
+        rgb_to_hsluv_slice(&rgb[..len], &mut hsluv[..len]);
+        for color in &mut hsluv[..len] {
+            *color = f(*color);
+        }
+        hsluv_to_rgb_slice(&hsluv[..len], &mut rgb[..len]);
+        for (pixel, rgb) in chunk.chunks_exact_mut(channels).zip(&rgb) {
+            P::from_slice_mut(pixel).set_color(*rgb);
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{get_bounds, hsluv_to_lch_with_bounds, Hsluv, Line, Luv, Rgb, Xyz};
+use core::iter::FusedIterator;
+
+// Evenly spaced hues in 0..360; 360 itself is skipped since it equals 0.
+fn hue_step(index: usize, steps: usize) -> f64 {
+    360.0 * index as f64 / steps as f64
+}
+
+// Evenly spaced values in 0..=100, both ends included.
+fn percent_step(index: usize, steps: usize) -> f64 {
+    if steps <= 1 {
+        0.0
+    } else {
+        100.0 * index as f64 / (steps - 1) as f64
+    }
+}
+
+fn sample(hsluv: Hsluv, bounds: &[Line; 6]) -> (Hsluv, Rgb) {
+    let lch = hsluv_to_lch_with_bounds(hsluv, bounds);
+    (hsluv, Rgb::from(Xyz::from(Luv::from(lch))))
+}
+
+/// Colors of a fixed saturation and lightness, evenly spaced around the hue
+/// circle starting at hue 0.
+#[derive(Debug, Clone)]
+pub struct HueWheel {
+    saturation: f64,
+    lightness: f64,
+    bounds: [Line; 6],
+    steps: usize,
+    index: usize,
+}
+
+impl HueWheel {
+    pub fn new(saturation: f64, lightness: f64, steps: usize) -> HueWheel {
+        HueWheel {
+            saturation,
+            lightness,
+            bounds: get_bounds(lightness),
+            steps,
+            index: 0,
+        }
+    }
+}
+
+impl Iterator for HueWheel {
+    type Item = (Hsluv, Rgb);
+
+    fn next(&mut self) -> Option<(Hsluv, Rgb)> {
+        if self.index >= self.steps {
+            return None;
+        }
+        let hsluv = Hsluv {
+            hue: hue_step(self.index, self.steps),
+            saturation: self.saturation,
+            lightness: self.lightness,
+        };
+        self.index += 1;
+        Some(sample(hsluv, &self.bounds))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.steps - self.index;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for HueWheel {}
+impl FusedIterator for HueWheel {}
+
+/// Colors of a fixed lightness over a grid of hue (columns, 0..360) by
+/// saturation (rows, 0..=100), in row-major order.
+#[derive(Debug, Clone)]
+pub struct HueSaturationPlane {
+    lightness: f64,
+    bounds: [Line; 6],
+    hue_steps: usize,
+    saturation_steps: usize,
+    index: usize,
+}
+
+impl HueSaturationPlane {
+    pub fn new(lightness: f64, hue_steps: usize, saturation_steps: usize) -> HueSaturationPlane {
+        HueSaturationPlane {
+            lightness,
+            bounds: get_bounds(lightness),
+            hue_steps,
+            saturation_steps,
+            index: 0,
+        }
+    }
+
+    fn sample_count(&self) -> usize {
+        self.hue_steps * self.saturation_steps
+    }
+}
+
+impl Iterator for HueSaturationPlane {
+    type Item = (Hsluv, Rgb);
+
+    fn next(&mut self) -> Option<(Hsluv, Rgb)> {
+        if self.index >= self.sample_count() {
+            return None;
+        }
+        let (row, column) = (self.index / self.hue_steps, self.index % self.hue_steps);
+        let hsluv = Hsluv {
+            hue: hue_step(column, self.hue_steps),
+            saturation: percent_step(row, self.saturation_steps),
+            lightness: self.lightness,
+        };
+        self.index += 1;
+        Some(sample(hsluv, &self.bounds))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.sample_count() - self.index;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for HueSaturationPlane {}
+impl FusedIterator for HueSaturationPlane {}
+
+/// Colors of a fixed hue over a grid of saturation (columns, 0..=100) by
+/// lightness (rows, 0..=100), in row-major order. Bounds are computed once
+/// per row.
+#[derive(Debug, Clone)]
+pub struct SaturationLightnessPlane {
+    hue: f64,
+    bounds: [Line; 6],
+    saturation_steps: usize,
+    lightness_steps: usize,
+    index: usize,
+}
+
+impl SaturationLightnessPlane {
+    pub fn new(
+        hue: f64,
+        saturation_steps: usize,
+        lightness_steps: usize,
+    ) -> SaturationLightnessPlane {
+        SaturationLightnessPlane {
+            hue,
+            bounds: get_bounds(0.0),
+            saturation_steps,
+            lightness_steps,
+            index: 0,
+        }
+    }
+
+    fn sample_count(&self) -> usize {
+        self.saturation_steps * self.lightness_steps
+    }
+}
+
+impl Iterator for SaturationLightnessPlane {
+    type Item = (Hsluv, Rgb);
+
+    fn next(&mut self) -> Option<(Hsluv, Rgb)> {
+        if self.index >= self.sample_count() {
+            return None;
+        }
+        let (row, column) = (
+            self.index / self.saturation_steps,
+            self.index % self.saturation_steps,
+        );
+        let lightness = percent_step(row, self.lightness_steps);
+        if column == 0 {
+            self.bounds = get_bounds(lightness);
+        }
+        let hsluv = Hsluv {
+            hue: self.hue,
+            saturation: percent_step(column, self.saturation_steps),
+            lightness,
+        };
+        self.index += 1;
+        Some(sample(hsluv, &self.bounds))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.sample_count() - self.index;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for SaturationLightnessPlane {}
+impl FusedIterator for SaturationLightnessPlane {}
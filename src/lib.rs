@@ -1,13 +1,51 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::excessive_precision)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::convert::TryFrom;
 use num_traits::float::Float;
 
+mod adjust;
+mod batch;
+#[cfg(feature = "capi")]
+pub mod capi;
+#[cfg(feature = "bytemuck")]
+mod cast;
+mod checked;
+mod compare;
+mod const_fns;
+mod convert;
+mod fixed;
+#[cfg(feature = "image")]
+mod image_buffer;
+mod iter;
+#[cfg(feature = "lut")]
+mod lut;
+#[cfg(feature = "palette")]
+mod palette_colors;
+mod raster;
 mod spaces;
+mod text;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub use batch::*;
+pub use checked::*;
+pub use compare::*;
+pub use convert::*;
+pub use fixed::*;
+#[cfg(feature = "image")]
+pub use image_buffer::*;
+pub use iter::*;
+pub use raster::*;
 pub use spaces::*;
+pub use text::*;
 
 // for RGB
-static M: [(f64, f64, f64); 3] = [
+const M: [(f64, f64, f64); 3] = [
     (
         3.24096994190452134377,
         -1.53738317757009345794,
@@ -26,7 +64,7 @@ static M: [(f64, f64, f64); 3] = [
 ];
 
 // for XYZ
-static M_INV: [(f64, f64, f64); 3] = [
+const M_INV: [(f64, f64, f64); 3] = [
     (
         0.41239079926595948129,
         0.35758433938387796373,
@@ -50,7 +88,8 @@ const REF_V: f64 = 0.46831999493879100370;
 const KAPPA: f64 = 903.29629629629629629630;
 const EPSILON: f64 = 0.00885645167903563082;
 
-fn get_bounds(lightness: f64) -> [Line; 6] {
+// `const` so the compile-time conversions in `const_fns` share it.
+const fn get_bounds(lightness: f64) -> [Line; 6] {
     let mut bounds = [Line {
         slope: 0.0,
         intercept: 0.0,
@@ -64,19 +103,24 @@ fn get_bounds(lightness: f64) -> [Line; 6] {
         lightness / KAPPA
     };
 
-    for (channel, m) in M.iter().enumerate() {
-        for t in 0u8..2u8 {
-            let t_f64 = f64::from(t);
+    let mut channel = 0;
+    while channel < M.len() {
+        let m = M[channel];
+        let mut t = 0;
+        while t < 2 {
+            let t_f64 = t as f64;
             let top1 = (284517.0 * m.0 - 94839.0 * m.2) * sub2;
             let top2 = (838422.0 * m.2 + 769860.0 * m.1 + 731718.0 * m.0) * lightness * sub2
                 - 769860.0 * t_f64 * lightness;
             let bottom = (632260.0 * m.2 - 126452.0 * m.1) * sub2 + 126452.0 * t_f64;
 
-            bounds[channel * 2 + usize::from(t)] = Line {
+            bounds[channel * 2 + t] = Line {
                 slope: top1 / bottom,
                 intercept: top2 / bottom,
             };
+            t += 1;
         }
+        channel += 1;
     }
 
     bounds
@@ -99,70 +143,223 @@ impl Line {
 }
 
 pub fn max_safe_chroma_for_lightness(lightness: f64) -> f64 {
-    get_bounds(lightness)
-        .iter()
-        .map(Line::distance_from_origin)
-        .fold(f64::MAX, f64::min)
+    max_safe_chroma_for_bounds(&get_bounds(lightness))
+}
+
+fn max_safe_chroma_for_bounds(bounds: &[Line; 6]) -> f64 {
+    closest_bound(bounds).distance_from_origin()
+}
+
+// The bound nearest the origin, compared by squared distance so that `const`
+// callers only need one square root.
+const fn closest_bound(bounds: &[Line; 6]) -> Line {
+    let mut closest = bounds[0];
+    let mut min = f64::MAX;
+    let mut i = 0;
+    while i < bounds.len() {
+        let line = bounds[i];
+        let distance = line.intercept * line.intercept / (line.slope * line.slope + 1.0);
+        if distance < min {
+            closest = line;
+            min = distance;
+        }
+        i += 1;
+    }
+    closest
 }
 
 pub fn max_chroma_for_lightness_hue(lightness: f64, hue: f64) -> f64 {
+    max_chroma_for_bounds_hue(&get_bounds(lightness), hue)
+}
+
+fn max_chroma_for_bounds_hue(bounds: &[Line; 6], hue: f64) -> f64 {
     // (2 * pi / 260)
     let hue_rad = hue * 0.01745329251994329577;
+    max_chroma_for_bounds_sin_cos(bounds, Float::sin(hue_rad), Float::cos(hue_rad))
+}
 
-    get_bounds(lightness)
-        .iter()
-        .map(|l| l.ray_length_until_intersect(hue_rad))
-        .filter(|length| length >= &0.0)
-        .fold(f64::MAX, f64::min)
+// The shortest ray at the hue with sine `sin` and cosine `cos` to reach a
+// bound, as in `Line::ray_length_until_intersect`.
+const fn max_chroma_for_bounds_sin_cos(bounds: &[Line; 6], sin: f64, cos: f64) -> f64 {
+    let mut min = f64::MAX;
+    let mut i = 0;
+    while i < bounds.len() {
+        let line = bounds[i];
+        let length = line.intercept / (sin - line.slope * cos);
+        if length >= 0.0 && length < min {
+            min = length;
+        }
+        i += 1;
+    }
+    min
 }
 
-fn dot_product<T, U, V>(lhs: (T, T, T), rhs: (U, U, U)) -> V
-where
-    T: core::ops::Mul<U, Output = V>,
-    V: core::ops::Add<Output = V>,
-{
-    lhs.0 * rhs.0 + lhs.1 * rhs.1 + lhs.2 * rhs.2
+// Like `max_chroma_for_lightness_hue`, but with white and black (where the
+// bounds degenerate) given no chroma.
+fn max_chroma_in_gamut(lightness: f64, hue: f64) -> f64 {
+    if !(0.00000001..=99.9999999).contains(&lightness) {
+        0.0
+    } else {
+        max_chroma_for_lightness_hue(lightness, hue)
+    }
 }
 
-impl From<Xyz> for Rgb {
-    fn from(xyz: Xyz) -> Rgb {
-        fn from_linear(c: f64) -> f64 {
-            if c <= 0.0031308 {
-                12.92 * c
+/// The most chromatic color of a hue. Maximum chroma over lightness is
+/// unimodal, rising from black to the cusp and falling again towards white.
+/// The cusp isn't always a primary: blue's hue peaks at a lighter mix of blue
+/// and white. A hue that isn't finite has no cusp, and gives all NaN.
+pub fn cusp_for_hue(hue: f64) -> Lch {
+    if !hue.is_finite() {
+        return Lch {
+            lightness: f64::NAN,
+            chroma: f64::NAN,
+            hue: f64::NAN,
+        };
+    }
+    // Golden-section search, narrowing (low, high) around the peak.
+    const INV_PHI: f64 = 0.61803398874989484820;
+    let (mut low, mut high) = (0.0, 100.0);
+    let mut a = high - (high - low) * INV_PHI;
+    let mut b = low + (high - low) * INV_PHI;
+    let (mut chroma_a, mut chroma_b) = (max_chroma_in_gamut(a, hue), max_chroma_in_gamut(b, hue));
+    for _ in 0..80 {
+        if chroma_a < chroma_b {
+            low = a;
+            a = b;
+            chroma_a = chroma_b;
+            b = low + (high - low) * INV_PHI;
+            chroma_b = max_chroma_in_gamut(b, hue);
+        } else {
+            high = b;
+            b = a;
+            chroma_b = chroma_a;
+            a = high - (high - low) * INV_PHI;
+            chroma_a = max_chroma_in_gamut(a, hue);
+        }
+    }
+    let lightness = (low + high) / 2.0;
+    Lch {
+        lightness,
+        chroma: max_chroma_in_gamut(lightness, hue),
+        hue: wrap_hue(hue),
+    }
+}
+
+/// The lightnesses at which a hue can reach `chroma` while staying in gamut,
+/// as an inclusive `(min, max)` pair, or `None` if `chroma` is beyond the
+/// hue's cusp or either argument isn't finite. Chromas within 1e-8 of the cusp
+/// give the cusp's lightness.
+pub fn lightness_range_for_chroma_hue(chroma: f64, hue: f64) -> Option<(f64, f64)> {
+    if !chroma.is_finite() || !hue.is_finite() {
+        return None;
+    }
+    if chroma <= 0.0 {
+        return Some((0.0, 100.0));
+    }
+    let cusp = cusp_for_hue(hue);
+    if chroma > cusp.chroma + 0.00000001 {
+        return None;
+    }
+    // Bisect each flank of the cusp for where max chroma crosses `chroma`.
+    let crossing = |mut outside: f64, mut inside: f64| {
+        for _ in 0..64 {
+            let middle = (outside + inside) / 2.0;
+            if max_chroma_in_gamut(middle, hue) >= chroma {
+                inside = middle;
             } else {
-                1.055 * Float::powf(c, 1.0 / 2.4) - 0.055
+                outside = middle;
             }
         }
+        inside
+    };
+    Some((
+        crossing(0.0, cusp.lightness),
+        crossing(100.0, cusp.lightness),
+    ))
+}
+
+pub const fn wrap_hue(hue: f64) -> f64 {
+    match hue % 360.0 {
+        // Tiny negative remainders round up to exactly 360.0
+        hue if hue < 0.0 => (hue + 360.0) % 360.0,
+        hue => hue,
+    }
+}
+
+const fn dot_product(lhs: (f64, f64, f64), rhs: (f64, f64, f64)) -> f64 {
+    lhs.0 * rhs.0 + lhs.1 * rhs.1 + lhs.2 * rhs.2
+}
+
+fn from_linear(c: f64) -> f64 {
+    if c <= 0.0031308 {
+        12.92 * c
+    } else {
+        #[cfg(feature = "lut")]
+        if let Some(power) = lut::FROM_LINEAR.get(c) {
+            return 1.055 * power - 0.055;
+        }
+        1.055 * Float::powf(c, 1.0 / 2.4) - 0.055
+    }
+}
+
+fn to_linear(c: f64) -> f64 {
+    if c > 0.04045 {
+        let x = (c + 0.055) / 1.055;
+        #[cfg(feature = "lut")]
+        if let Some(power) = lut::TO_LINEAR.get(x) {
+            return power;
+        }
+        Float::powf(x, 2.4)
+    } else {
+        c / 12.92
+    }
+}
+
+fn rgb8_to_linear(c: u8) -> f64 {
+    #[cfg(feature = "lut")]
+    return lut::RGB8_TO_LINEAR[usize::from(c)];
+    #[cfg(not(feature = "lut"))]
+    to_linear(f64::from(c) / 255.0)
+}
+
+// The conversions below that need no roots, powers or trigonometry are `const`
+// and shared with `const_fns`, which supplies its own versions of those.
 
-        let xyz = xyz.xyz();
+const fn xyz_to_linear_rgb(xyz: Xyz) -> (f64, f64, f64) {
+    let xyz = (xyz.x, xyz.y, xyz.z);
+    (
+        dot_product(M[0], xyz),
+        dot_product(M[1], xyz),
+        dot_product(M[2], xyz),
+    )
+}
+
+impl From<Xyz> for Rgb {
+    fn from(xyz: Xyz) -> Rgb {
+        let (red, green, blue) = xyz_to_linear_rgb(xyz);
         Rgb {
-            red: from_linear(dot_product(M[0], xyz)),
-            green: from_linear(dot_product(M[1], xyz)),
-            blue: from_linear(dot_product(M[2], xyz)),
+            red: from_linear(red),
+            green: from_linear(green),
+            blue: from_linear(blue),
         }
     }
 }
 
+const fn linear_rgb_to_xyz(rgb_linear: (f64, f64, f64)) -> Xyz {
+    Xyz {
+        x: dot_product(M_INV[0], rgb_linear),
+        y: dot_product(M_INV[1], rgb_linear),
+        z: dot_product(M_INV[2], rgb_linear),
+    }
+}
+
 impl From<Rgb> for Xyz {
     fn from(rgb: Rgb) -> Xyz {
-        fn to_linear(c: f64) -> f64 {
-            if c > 0.04045 {
-                Float::powf((c + 0.055) / 1.055, 2.4)
-            } else {
-                c / 12.92
-            }
-        }
-
-        let rgb_linear = (
+        linear_rgb_to_xyz((
             to_linear(rgb.red),
             to_linear(rgb.green),
             to_linear(rgb.blue),
-        );
-        Xyz {
-            x: dot_product(M_INV[0], rgb_linear),
-            y: dot_product(M_INV[1], rgb_linear),
-            z: dot_product(M_INV[2], rgb_linear),
-        }
+        ))
     }
 }
 
@@ -170,11 +367,15 @@ pub fn y_to_lightness(y: f64) -> f64 {
     if y <= EPSILON {
         y * KAPPA
     } else {
+        #[cfg(feature = "lut")]
+        if let Some(root) = lut::CBRT.get(y) {
+            return 116.0 * root - 16.0;
+        }
         116.0 * Float::cbrt(y) - 16.0
     }
 }
 
-pub fn lightness_to_y(lightness: f64) -> f64 {
+pub const fn lightness_to_y(lightness: f64) -> f64 {
     if lightness <= 8.0 {
         lightness / KAPPA
     } else {
@@ -183,68 +384,83 @@ pub fn lightness_to_y(lightness: f64) -> f64 {
     }
 }
 
+// `lightness` must be `y_to_lightness(xyz.y)`.
+const fn xyz_to_luv(xyz: Xyz, lightness: f64) -> Luv {
+    let var_u = (4.0 * xyz.x) / (xyz.x + (15.0 * xyz.y) + (3.0 * xyz.z));
+    let var_v = (9.0 * xyz.y) / (xyz.x + (15.0 * xyz.y) + (3.0 * xyz.z));
+
+    if lightness < 0.00000001 {
+        Luv {
+            lightness,
+            u: 0.0,
+            v: 0.0,
+        }
+    } else {
+        Luv {
+            lightness,
+            u: 13.0 * lightness * (var_u - REF_U),
+            v: 13.0 * lightness * (var_v - REF_V),
+        }
+    }
+}
+
 impl From<Xyz> for Luv {
     fn from(xyz: Xyz) -> Luv {
-        let var_u = (4.0 * xyz.x) / (xyz.x + (15.0 * xyz.y) + (3.0 * xyz.z));
-        let var_v = (9.0 * xyz.y) / (xyz.x + (15.0 * xyz.y) + (3.0 * xyz.z));
-        let lightness = y_to_lightness(xyz.y);
-
-        if lightness < 0.00000001 {
-            Luv {
-                lightness,
-                u: 0.0,
-                v: 0.0,
-            }
-        } else {
-            Luv {
-                lightness,
-                u: 13.0 * lightness * (var_u - REF_U),
-                v: 13.0 * lightness * (var_v - REF_V),
-            }
+        xyz_to_luv(xyz, y_to_lightness(xyz.y))
+    }
+}
+
+const fn luv_to_xyz(luv: Luv) -> Xyz {
+    if luv.lightness <= 0.00000001 {
+        // Black will create a divide-by-zero error.
+        Xyz {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        }
+    } else {
+        let var_u = luv.u / (13.0 * luv.lightness) + REF_U;
+        let var_v = luv.v / (13.0 * luv.lightness) + REF_V;
+        let y = lightness_to_y(luv.lightness);
+        let x = -(9.0 * y * var_u) / ((var_u - 4.0) * var_v - var_u * var_v);
+        Xyz {
+            x,
+            y,
+            z: (9.0 * y - (15.0 * var_v * y) - (var_v * x)) / (3.0 * var_v),
         }
     }
 }
 
 impl From<Luv> for Xyz {
     fn from(luv: Luv) -> Xyz {
-        if luv.lightness <= 0.00000001 {
-            // Black will create a divide-by-zero error.
-            Xyz {
-                x: 0.0,
-                y: 0.0,
-                z: 0.0,
-            }
-        } else {
-            let var_u = luv.u / (13.0 * luv.lightness) + REF_U;
-            let var_v = luv.v / (13.0 * luv.lightness) + REF_V;
-            let y = lightness_to_y(luv.lightness);
-            let x = -(9.0 * y * var_u) / ((var_u - 4.0) * var_v - var_u * var_v);
-            Xyz {
-                x,
-                y,
-                z: (9.0 * y - (15.0 * var_v * y) - (var_v * x)) / (3.0 * var_v),
-            }
-        }
+        luv_to_xyz(luv)
+    }
+}
+
+// `chroma` and `hue` are the polar form of `luv`'s u and v, hue in degrees.
+const fn luv_to_lch(luv: Luv, chroma: f64, hue: f64) -> Lch {
+    Lch {
+        lightness: luv.lightness,
+        chroma,
+        hue: gray_hue(chroma, hue),
     }
 }
 
 impl From<Luv> for Lch {
     fn from(luv: Luv) -> Lch {
         let chroma = Float::sqrt(luv.u * luv.u + luv.v * luv.v);
+        // (180 / pi)
+        let hue = Float::atan2(luv.v, luv.u) * 57.29577951308232087680;
+        luv_to_lch(luv, chroma, hue)
+    }
+}
 
-        Lch {
-            lightness: luv.lightness,
-            chroma,
-            hue: if chroma < 0.00000001 {
-                0.0
-            } else {
-                // (180 / pi)
-                match Float::atan2(luv.v, luv.u) * 57.29577951308232087680 {
-                    hue if hue < 0.0 => hue + 360.0,
-                    hue => hue,
-                }
-            },
-        }
+// `sin` and `cos` are of `lch`'s hue.
+const fn lch_to_luv(lch: Lch, sin: f64, cos: f64) -> Luv {
+    Luv {
+        lightness: lch.lightness,
+        u: cos * lch.chroma,
+        v: sin * lch.chroma,
     }
 }
 
@@ -252,91 +468,98 @@ impl From<Lch> for Luv {
     fn from(lch: Lch) -> Luv {
         // (pi / 180)
         let hue_rad = lch.hue * 0.01745329251994329577;
+        lch_to_luv(lch, Float::sin(hue_rad), Float::cos(hue_rad))
+    }
+}
 
-        Luv {
-            lightness: lch.lightness,
-            u: Float::cos(hue_rad) * lch.chroma,
-            v: Float::sin(hue_rad) * lch.chroma,
-        }
+// Where the bounds degenerate, so chroma and saturation are taken as 0.
+const fn is_white_or_black(lightness: f64) -> bool {
+    lightness > 99.9999999 || lightness < 0.00000001
+}
+
+// Grays, with `amount` of chroma or saturation too small to have a hue, get
+// hue 0.
+const fn gray_hue(amount: f64, hue: f64) -> f64 {
+    if amount < 0.00000001 {
+        0.0
+    } else {
+        wrap_hue(hue)
+    }
+}
+
+// Shared by HSLuv and HPLuv. `max_chroma` is the chroma that saturation 100
+// reaches at `lightness`, and `hue` for HSLuv.
+const fn hsl_to_lch(hue: f64, saturation: f64, lightness: f64, max_chroma: f64) -> Lch {
+    Lch {
+        lightness,
+        chroma: if is_white_or_black(lightness) {
+            0.0
+        } else {
+            max_chroma / 100.0 * saturation
+        },
+        hue: gray_hue(saturation, hue),
     }
 }
 
+// Returns (hue, saturation, lightness), with `max_chroma` as for `hsl_to_lch`.
+const fn lch_to_hsl(lch: Lch, max_chroma: f64) -> (f64, f64, f64) {
+    (
+        gray_hue(lch.chroma, lch.hue),
+        if is_white_or_black(lch.lightness) {
+            0.0
+        } else {
+            lch.chroma / max_chroma * 100.0
+        },
+        lch.lightness,
+    )
+}
+
 impl From<Hsluv> for Lch {
     fn from(hsluv: Hsluv) -> Lch {
-        Lch {
-            lightness: hsluv.lightness,
-            // White and black: disambiguate chroma
-            chroma: if hsluv.lightness > 99.9999999 || hsluv.lightness < 0.00000001 {
-                0.0
-            } else {
-                max_chroma_for_lightness_hue(hsluv.lightness, hsluv.hue) / 100.0 * hsluv.saturation
-            },
-            // Grays: disambiguate hue
-            hue: if hsluv.saturation < 0.00000001 {
-                0.0
-            } else {
-                hsluv.hue
-            },
-        }
+        hsluv_to_lch_with_bounds(hsluv, &get_bounds(hsluv.lightness))
     }
 }
 
+// `bounds` must be `get_bounds(hsluv.lightness)`; callers sampling many colors
+// of one lightness compute them once and share them.
+fn hsluv_to_lch_with_bounds(hsluv: Hsluv, bounds: &[Line; 6]) -> Lch {
+    let max_chroma = max_chroma_for_bounds_hue(bounds, hsluv.hue);
+    hsl_to_lch(hsluv.hue, hsluv.saturation, hsluv.lightness, max_chroma)
+}
+
 impl From<Lch> for Hsluv {
     fn from(lch: Lch) -> Hsluv {
+        let max_chroma = max_chroma_for_lightness_hue(lch.lightness, lch.hue);
+        let (hue, saturation, lightness) = lch_to_hsl(lch, max_chroma);
         Hsluv {
-            // Grays: disambiguate hue
-            hue: if lch.chroma < 0.00000001 {
-                0.0
-            } else {
-                lch.hue
-            },
-            // White and black: disambiguate saturation
-            saturation: if lch.lightness > 99.9999999 || lch.lightness < 0.00000001 {
-                0.0
-            } else {
-                lch.chroma / max_chroma_for_lightness_hue(lch.lightness, lch.hue) * 100.0
-            },
-            lightness: lch.lightness,
+            hue,
+            saturation,
+            lightness,
         }
     }
 }
 
 impl From<Hpluv> for Lch {
     fn from(hpluv: Hpluv) -> Lch {
-        Lch {
-            lightness: hpluv.lightness,
-            // White and black: disambiguate chroma
-            chroma: if hpluv.lightness > 99.9999999 || hpluv.lightness < 0.00000001 {
-                0.0
-            } else {
-                max_safe_chroma_for_lightness(hpluv.lightness) / 100.0 * hpluv.saturation
-            },
-            // Grays: disambiguate hue
-            hue: if hpluv.saturation < 0.00000001 {
-                0.0
-            } else {
-                hpluv.hue
-            },
-        }
+        hpluv_to_lch_with_bounds(hpluv, &get_bounds(hpluv.lightness))
     }
 }
 
+// `bounds` must be `get_bounds(hpluv.lightness)`, as with
+// `hsluv_to_lch_with_bounds`.
+fn hpluv_to_lch_with_bounds(hpluv: Hpluv, bounds: &[Line; 6]) -> Lch {
+    let max_chroma = max_safe_chroma_for_bounds(bounds);
+    hsl_to_lch(hpluv.hue, hpluv.saturation, hpluv.lightness, max_chroma)
+}
+
 impl From<Lch> for Hpluv {
     fn from(lch: Lch) -> Hpluv {
+        let max_chroma = max_safe_chroma_for_lightness(lch.lightness);
+        let (hue, saturation, lightness) = lch_to_hsl(lch, max_chroma);
         Hpluv {
-            // Grays: disambiguate hue
-            hue: if lch.chroma < 0.00000001 {
-                0.0
-            } else {
-                lch.hue
-            },
-            // White and black: disambiguate saturation
-            saturation: if lch.lightness > 99.9999999 || lch.lightness < 0.00000001 {
-                0.0
-            } else {
-                lch.chroma / max_safe_chroma_for_lightness(lch.lightness) * 100.0
-            },
-            lightness: lch.lightness,
+            hue,
+            saturation,
+            lightness,
         }
     }
 }
@@ -362,6 +585,55 @@ impl From<Rgb> for Hpluv {
     }
 }
 
+/// HPLuv saturation goes past 100 for colors more chromatic than every hue
+/// can reach at their lightness; `Hpluv::try_from_hsluv` reports those.
+impl From<Hsluv> for Hpluv {
+    fn from(hsluv: Hsluv) -> Hpluv {
+        Hpluv::from(Lch::from(hsluv))
+    }
+}
+
+// Allows for the drift of converting through `Lch`.
+fn saturation_in_range(saturation: f64) -> bool {
+    (-0.00000001..=100.00000001).contains(&saturation)
+}
+
+impl Hpluv {
+    /// `Err` when the color is too chromatic for HPLuv, as when a UI
+    /// switching from HSLuv to HPLuv should fall back to HSLuv.
+    pub fn try_from_hsluv(hsluv: Hsluv) -> Result<Hpluv, SaturationOutOfRange<Hpluv>> {
+        let hpluv = Hpluv::from(hsluv);
+        if saturation_in_range(hpluv.saturation) {
+            Ok(hpluv)
+        } else {
+            Err(SaturationOutOfRange(hpluv))
+        }
+    }
+}
+
+/// Every HPLuv color with saturation up to 100 is in gamut; beyond that,
+/// the result may be outside the sRGB gamut and is reported as an `Err`.
+impl TryFrom<Hpluv> for Hsluv {
+    type Error = SaturationOutOfRange<Hsluv>;
+
+    fn try_from(hpluv: Hpluv) -> Result<Hsluv, SaturationOutOfRange<Hsluv>> {
+        let hsluv = Hsluv::from(Lch::from(hpluv));
+        if saturation_in_range(hsluv.saturation) {
+            Ok(hsluv)
+        } else {
+            Err(SaturationOutOfRange(hsluv))
+        }
+    }
+}
+
+/// The largest difference in any channel between an in-gamut `Rgb` and the
+/// same color after a round trip through `Hsluv` or `Hpluv`.
+///
+/// Most colors come back within 1e-13. The bound is set by colors within
+/// 1e-7 lightness of white, which have their saturation dropped to 0 like
+/// white itself.
+pub const ROUND_TRIP_ERROR: f64 = 2e-8;
+
 pub fn hsluv_to_rgb(hue: f64, saturation: f64, lightness: f64) -> (f64, f64, f64) {
     Rgb::from(Hsluv {
         hue,
@@ -385,5 +657,30 @@ pub fn rgb_to_hpluv(red: f64, green: f64, blue: f64) -> (f64, f64, f64) {
     Hpluv::from(Rgb { red, green, blue }).hsl()
 }
 
+#[cfg(feature = "alloc")]
+pub fn hsluv_to_hex(hue: f64, saturation: f64, lightness: f64) -> String {
+    Rgb::from(Hsluv {
+        hue,
+        saturation,
+        lightness,
+    })
+    .to_hex()
+}
+#[cfg(feature = "alloc")]
+pub fn hpluv_to_hex(hue: f64, saturation: f64, lightness: f64) -> String {
+    Rgb::from(Hpluv {
+        hue,
+        saturation,
+        lightness,
+    })
+    .to_hex()
+}
+pub fn hex_to_hsluv(hex: &str) -> Result<(f64, f64, f64), HexError> {
+    Rgb::from_hex(hex).map(|rgb| Hsluv::from(rgb).hsl())
+}
+pub fn hex_to_hpluv(hex: &str) -> Result<(f64, f64, f64), HexError> {
+    Rgb::from_hex(hex).map(|rgb| Hpluv::from(rgb).hsl())
+}
+
 #[cfg(test)]
 mod tests;
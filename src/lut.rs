@@ -0,0 +1,112 @@
+//! Lookup tables for the sRGB transfer function and the lightness curve,
+//! enabled by the `lut` feature.
+//!
+//! The `powf` and `cbrt` calls of `to_linear`, `from_linear` and
+//! `y_to_lightness` become cubic Hermite interpolation between knots holding
+//! the curve's value and slope. Knots are spaced 512 to an octave, so a knot
+//! is found by shifting the input's bits, and they crowd towards zero where
+//! the curves bend hardest. The interpolated curves are within 1e-13 of the
+//! exact ones. That keeps every snapshot conversion within 1e-8, including
+//! Hpluv saturations near white, which amplify errors a thousandfold.
+//! Inputs outside a table (out-of-gamut colors) fall back to the exact math.
+//!
+//! `lightness_to_y` is a cube already and isn't tabulated. 8-bit channels
+//! skip interpolation entirely through a 256-entry table. All tables take
+//! about 162 KiB.
+
+use crate::const_fns::{root, to_linear};
+
+const KNOTS_PER_OCTAVE_BITS: u32 = 9;
+const KNOT_SHIFT: u32 = 52 - KNOTS_PER_OCTAVE_BITS;
+
+#[derive(Clone, Copy)]
+enum Power {
+    // x^2.4
+    ToLinear,
+    // x^(1 / 2.4)
+    FromLinear,
+    // x^(1 / 3)
+    Cbrt,
+}
+
+impl Power {
+    // Returns (x^p, d/dx x^p).
+    const fn eval(self, x: f64) -> (f64, f64) {
+        let (value, exponent) = match self {
+            Power::ToLinear => {
+                let r = root(x, 5);
+                (x * x * r * r, 2.4)
+            }
+            Power::FromLinear => {
+                let r = root(x, 12);
+                (r * r * r * r * r, 1.0 / 2.4)
+            }
+            Power::Cbrt => (root(x, 3), 1.0 / 3.0),
+        };
+        (value, exponent * value / x)
+    }
+}
+
+pub(crate) struct Curve<const N: usize> {
+    // Bits of the lowest knot, a power of two.
+    base: u64,
+    // Value and slope at each knot, ending with the knot at 1.0.
+    knots: [(f64, f64); N],
+}
+
+impl<const N: usize> Curve<N> {
+    // `N` must be `octaves * 512 + 1` to end on 1.0.
+    const fn new(octaves: u64, power: Power) -> Curve<N> {
+        let base = (1023 - octaves) << 52;
+        let mut knots = [(0.0, 0.0); N];
+        let mut i = 0;
+        while i < N {
+            knots[i] = power.eval(f64::from_bits(base + ((i as u64) << KNOT_SHIFT)));
+            i += 1;
+        }
+        Curve { base, knots }
+    }
+
+    fn knot(&self, i: usize) -> f64 {
+        f64::from_bits(self.base + ((i as u64) << KNOT_SHIFT))
+    }
+
+    /// The curve at `x`, or `None` outside of its table.
+    pub(crate) fn get(&self, x: f64) -> Option<f64> {
+        #[allow(clippy::manual_range_contains)]
+        if !(x >= f64::from_bits(self.base) && x < 1.0) {
+            return None;
+        }
+        let i = ((x.to_bits() - self.base) >> KNOT_SHIFT) as usize;
+        let (x0, x1) = (self.knot(i), self.knot(i + 1));
+        let (y0, slope0) = self.knots[i];
+        let (y1, slope1) = self.knots[i + 1];
+        let h = x1 - x0;
+        let t = (x - x0) / h;
+        let t2 = t * t;
+        let t3 = t2 * t;
+        Some(
+            (2.0 * t3 - 3.0 * t2 + 1.0) * y0
+                + (t3 - 2.0 * t2 + t) * h * slope0
+                + (3.0 * t2 - 2.0 * t3) * y1
+                + (t3 - t2) * h * slope1,
+        )
+    }
+}
+
+// (0.04045 + 0.055) / 1.055 > 2^-4
+pub(crate) static TO_LINEAR: Curve<{ 4 * 512 + 1 }> = Curve::new(4, Power::ToLinear);
+// 0.0031308 > 2^-9
+pub(crate) static FROM_LINEAR: Curve<{ 9 * 512 + 1 }> = Curve::new(9, Power::FromLinear);
+// EPSILON > 2^-7
+pub(crate) static CBRT: Curve<{ 7 * 512 + 1 }> = Curve::new(7, Power::Cbrt);
+
+pub(crate) static RGB8_TO_LINEAR: [f64; 256] = {
+    let mut table = [0.0; 256];
+    let mut i = 0;
+    while i < table.len() {
+        table[i] = to_linear(i as f64 / 255.0);
+        i += 1;
+    }
+    table
+};
@@ -0,0 +1,145 @@
+//! Conversions to and from [`palette`] colors, enabled by the `palette`
+//! feature.
+//!
+//! Each `From` impl only moves components between fields; hues come back
+//! wrapped into 0..360. palette's white point differs from HSLuv's in the
+//! fifth decimal place, so converting between spaces on the palette side
+//! gives slightly different results than doing it here. With both crates'
+//! `IntoColor` in scope, `into_color` still works on palette colors, but on
+//! this crate's needs a path: `hsluv::IntoColor::into_color(color)`.
+//!
+//! `Hsluv` and `Hpluv` implement palette's `Mix`, for blending and building
+//! gradients without leaving HSLuv. Hue takes the shorter way around.
+
+use crate::{wrap_hue, Hpluv, Hsluv, Lch, Luv, Rgb, Xyz};
+use palette::{white_point::D65, LuvHue, Mix, MixAssign};
+
+impl From<palette::Srgb<f64>> for Rgb {
+    fn from(rgb: palette::Srgb<f64>) -> Rgb {
+        Rgb {
+            red: rgb.red,
+            green: rgb.green,
+            blue: rgb.blue,
+        }
+    }
+}
+
+impl From<Rgb> for palette::Srgb<f64> {
+    fn from(rgb: Rgb) -> palette::Srgb<f64> {
+        palette::Srgb::new(rgb.red, rgb.green, rgb.blue)
+    }
+}
+
+impl From<palette::Xyz<D65, f64>> for Xyz {
+    fn from(xyz: palette::Xyz<D65, f64>) -> Xyz {
+        Xyz {
+            x: xyz.x,
+            y: xyz.y,
+            z: xyz.z,
+        }
+    }
+}
+
+impl From<Xyz> for palette::Xyz<D65, f64> {
+    fn from(xyz: Xyz) -> palette::Xyz<D65, f64> {
+        palette::Xyz::new(xyz.x, xyz.y, xyz.z)
+    }
+}
+
+impl From<palette::Luv<D65, f64>> for Luv {
+    fn from(luv: palette::Luv<D65, f64>) -> Luv {
+        Luv {
+            lightness: luv.l,
+            u: luv.u,
+            v: luv.v,
+        }
+    }
+}
+
+impl From<Luv> for palette::Luv<D65, f64> {
+    fn from(luv: Luv) -> palette::Luv<D65, f64> {
+        palette::Luv::new(luv.lightness, luv.u, luv.v)
+    }
+}
+
+impl From<palette::Lchuv<D65, f64>> for Lch {
+    fn from(lch: palette::Lchuv<D65, f64>) -> Lch {
+        Lch {
+            lightness: lch.l,
+            chroma: lch.chroma,
+            hue: lch.hue.into_positive_degrees(),
+        }
+    }
+}
+
+impl From<Lch> for palette::Lchuv<D65, f64> {
+    fn from(lch: Lch) -> palette::Lchuv<D65, f64> {
+        palette::Lchuv::new(lch.lightness, lch.chroma, LuvHue::from_degrees(lch.hue))
+    }
+}
+
+impl From<palette::Hsluv<D65, f64>> for Hsluv {
+    fn from(hsluv: palette::Hsluv<D65, f64>) -> Hsluv {
+        Hsluv {
+            hue: hsluv.hue.into_positive_degrees(),
+            saturation: hsluv.saturation,
+            lightness: hsluv.l,
+        }
+    }
+}
+
+impl From<Hsluv> for palette::Hsluv<D65, f64> {
+    fn from(hsluv: Hsluv) -> palette::Hsluv<D65, f64> {
+        palette::Hsluv::new(
+            LuvHue::from_degrees(hsluv.hue),
+            hsluv.saturation,
+            hsluv.lightness,
+        )
+    }
+}
+
+// Returns (hue, saturation, lightness).
+fn mix_hsl(
+    (hue, saturation, lightness): (f64, f64, f64),
+    (other_hue, other_saturation, other_lightness): (f64, f64, f64),
+    factor: f64,
+) -> (f64, f64, f64) {
+    let factor = factor.clamp(0.0, 1.0);
+    let mut hue_difference = wrap_hue(other_hue - hue);
+    if hue_difference > 180.0 {
+        hue_difference -= 360.0;
+    }
+    (
+        wrap_hue(hue + factor * hue_difference),
+        saturation + factor * (other_saturation - saturation),
+        lightness + factor * (other_lightness - lightness),
+    )
+}
+
+macro_rules! impl_mix {
+    ($t:ident) => {
+        impl Mix for $t {
+            type Scalar = f64;
+
+            fn mix(self, other: $t, factor: f64) -> $t {
+                let (hue, saturation, lightness) = mix_hsl(self.hsl(), other.hsl(), factor);
+                $t {
+                    hue,
+                    saturation,
+                    lightness,
+                }
+            }
+        }
+
+        impl MixAssign for $t {
+            type Scalar = f64;
+
+            fn mix_assign(&mut self, other: $t, factor: f64) {
+                *self = self.mix(other, factor);
+            }
+        }
+    };
+}
+
+impl_mix!(Hsluv);
+impl_mix!(Hpluv);
@@ -0,0 +1,223 @@
+//! Rendering of color picker backgrounds into caller-supplied RGBA8 buffers.
+//!
+//! Buffers are tightly packed rows of `width * 4` bytes, top row first. Every
+//! function panics if the buffer is not exactly `width * height * 4` bytes.
+
+use crate::{
+    get_bounds, hpluv_to_lch_with_bounds, hsluv_to_lch_with_bounds, max_chroma_for_bounds_hue,
+    wrap_hue, Hpluv, Hsluv, Lch, Line, Luv, Rgb, Xyz,
+};
+use num_traits::float::Float;
+
+// How far outside 0..=1 a channel may drift before counting as out of gamut.
+const GAMUT_TOLERANCE: f64 = 0.00000001;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PickerSpace {
+    Hsluv,
+    Hpluv,
+}
+
+fn check_buffer(pixels: &[u8], width: usize, height: usize) {
+    assert_eq!(
+        pixels.len(),
+        width * height * 4,
+        "RGBA8 buffer length doesn't match {}x{}",
+        width,
+        height
+    );
+}
+
+fn in_gamut(rgb: Rgb) -> bool {
+    let range = -GAMUT_TOLERANCE..=1.0 + GAMUT_TOLERANCE;
+    range.contains(&rgb.red) && range.contains(&rgb.green) && range.contains(&rgb.blue)
+}
+
+fn rgba8(rgb: Rgb) -> [u8; 4] {
+    let [red, green, blue] = rgb.to_rgb8();
+    [red, green, blue, 255]
+}
+
+fn lch_to_rgb(lch: Lch) -> Rgb {
+    Rgb::from(Xyz::from(Luv::from(lch)))
+}
+
+fn paint(pixel: &mut [u8], rgb: Rgb, out_of_gamut: Option<[u8; 4]>) {
+    let rgba = match out_of_gamut {
+        Some(mark) if !in_gamut(rgb) => mark,
+        _ => rgba8(rgb),
+    };
+    pixel.copy_from_slice(&rgba);
+}
+
+// Maps a pixel center to coordinates in -1..=1 around the buffer center, y up,
+// scaled so the largest centered square fits.
+fn disk_coordinates(x: usize, y: usize, width: usize, height: usize) -> (f64, f64) {
+    let half = width.min(height) as f64 / 2.0;
+    (
+        (x as f64 + 0.5 - width as f64 / 2.0) / half,
+        (height as f64 / 2.0 - (y as f64 + 0.5)) / half,
+    )
+}
+
+fn polar_degrees(x: f64, y: f64) -> (f64, f64) {
+    // (180 / pi)
+    let hue = wrap_hue(Float::atan2(y, x) * 57.29577951308232087680);
+    (Float::sqrt(x * x + y * y), hue)
+}
+
+// Distance from the origin to the farthest corner of the gamut polygon.
+fn gamut_radius(bounds: &[Line; 6]) -> f64 {
+    let mut radius = 0.0;
+    for (i, a) in bounds.iter().enumerate() {
+        for b in &bounds[i + 1..] {
+            let u = (b.intercept - a.intercept) / (a.slope - b.slope);
+            let (length, hue) = polar_degrees(u, a.slope * u + a.intercept);
+            if length.is_finite() && length <= max_chroma_for_bounds_hue(bounds, hue) + 0.000001 {
+                radius = Float::max(radius, length);
+            }
+        }
+    }
+    radius
+}
+
+/// Renders the hue/saturation disk of `space` at `lightness`: hue runs
+/// counter-clockwise from the positive x axis, saturation outward from 0 at
+/// the center to 100 at the edge. Pixels outside the disk become transparent.
+/// Lightnesses outside 0..=100 leave the sRGB gamut; those pixels are painted
+/// `out_of_gamut` if given, and clamped otherwise.
+pub fn render_hue_saturation_disk(
+    pixels: &mut [u8],
+    width: usize,
+    height: usize,
+    space: PickerSpace,
+    lightness: f64,
+    out_of_gamut: Option<[u8; 4]>,
+) {
+    check_buffer(pixels, width, height);
+    let bounds = get_bounds(lightness);
+    for (i, pixel) in pixels.chunks_exact_mut(4).enumerate() {
+        let (x, y) = disk_coordinates(i % width, i / width, width, height);
+        let (radius, hue) = polar_degrees(x, y);
+        if radius > 1.0 {
+            pixel.copy_from_slice(&[0, 0, 0, 0]);
+            continue;
+        }
+        let saturation = radius * 100.0;
+        let lch = match space {
+            PickerSpace::Hsluv => hsluv_to_lch_with_bounds(
+                Hsluv {
+                    hue,
+                    saturation,
+                    lightness,
+                },
+                &bounds,
+            ),
+            PickerSpace::Hpluv => hpluv_to_lch_with_bounds(
+                Hpluv {
+                    hue,
+                    saturation,
+                    lightness,
+                },
+                &bounds,
+            ),
+        };
+        paint(pixel, lch_to_rgb(lch), out_of_gamut);
+    }
+}
+
+/// Renders a horizontal strip of `space` colors at a fixed hue and
+/// saturation, with lightness running from 0 at the left to 100 at the right.
+/// Saturations above 100 leave the sRGB gamut; those pixels are painted
+/// `out_of_gamut` if given, and clamped otherwise.
+pub fn render_lightness_strip(
+    pixels: &mut [u8],
+    width: usize,
+    height: usize,
+    space: PickerSpace,
+    hue: f64,
+    saturation: f64,
+    out_of_gamut: Option<[u8; 4]>,
+) {
+    check_buffer(pixels, width, height);
+    if pixels.is_empty() {
+        return;
+    }
+    let (first_row, rest) = pixels.split_at_mut(width * 4);
+    for (x, pixel) in first_row.chunks_exact_mut(4).enumerate() {
+        let lightness = if width == 1 {
+            0.0
+        } else {
+            100.0 * x as f64 / (width - 1) as f64
+        };
+        let rgb = match space {
+            PickerSpace::Hsluv => Rgb::from(Hsluv {
+                hue,
+                saturation,
+                lightness,
+            }),
+            PickerSpace::Hpluv => Rgb::from(Hpluv {
+                hue,
+                saturation,
+                lightness,
+            }),
+        };
+        paint(pixel, rgb, out_of_gamut);
+    }
+    for row in rest.chunks_exact_mut(width * 4) {
+        row.copy_from_slice(first_row);
+    }
+}
+
+/// Renders the CIELUV chroma plane at `lightness`: u runs left to right and v
+/// bottom to top, scaled so the sRGB gamut polygon just fits. Colors outside
+/// the polygon are painted `out_of_gamut` if given, and clamped otherwise.
+pub fn render_luv_plane(
+    pixels: &mut [u8],
+    width: usize,
+    height: usize,
+    lightness: f64,
+    out_of_gamut: Option<[u8; 4]>,
+) {
+    check_buffer(pixels, width, height);
+    let scale = luv_plane_scale(&get_bounds(lightness));
+    for (i, pixel) in pixels.chunks_exact_mut(4).enumerate() {
+        let (u, v) = disk_coordinates(i % width, i / width, width, height);
+        let luv = Luv {
+            lightness,
+            u: u * scale,
+            v: v * scale,
+        };
+        paint(pixel, Rgb::from(Xyz::from(luv)), out_of_gamut);
+    }
+}
+
+fn luv_plane_scale(bounds: &[Line; 6]) -> f64 {
+    match gamut_radius(bounds) {
+        // Black and white collapse the polygon to a point.
+        radius if radius > 0.0 => radius,
+        _ => 1.0,
+    }
+}
+
+/// Draws the outline of the sRGB gamut polygon at `lightness` over a buffer
+/// laid out like `render_luv_plane`, leaving other pixels untouched.
+pub fn render_gamut_polygon(
+    pixels: &mut [u8],
+    width: usize,
+    height: usize,
+    lightness: f64,
+    color: [u8; 4],
+) {
+    check_buffer(pixels, width, height);
+    let bounds = get_bounds(lightness);
+    let scale = luv_plane_scale(&bounds);
+    let half_pixel = scale / width.min(height).max(1) as f64;
+    for (i, pixel) in pixels.chunks_exact_mut(4).enumerate() {
+        let (u, v) = disk_coordinates(i % width, i / width, width, height);
+        let (chroma, hue) = polar_degrees(u * scale, v * scale);
+        if Float::abs(chroma - max_chroma_for_bounds_hue(&bounds, hue)) <= half_pixel {
+            pixel.copy_from_slice(&color);
+        }
+    }
+}
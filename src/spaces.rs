@@ -1,3 +1,22 @@
+use crate::{wrap_hue, M_INV};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use num_traits::float::Float;
+
+/// How far outside its range a component may drift and still be clamped
+/// into it by the `normalize` methods. For a range of 0..=max the slack is
+/// `NORMALIZE_TOLERANCE * max`, so 0.0001 on 0..=100 and 0.000001 on 0..=1.
+pub const NORMALIZE_TOLERANCE: f64 = 0.000001;
+
+fn normalize_component(value: f64, max: f64) -> Option<f64> {
+    let slack = max * NORMALIZE_TOLERANCE;
+    if (-slack..=max + slack).contains(&value) {
+        Some(value.clamp(0.0, max))
+    } else {
+        None
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum RgbBounds {
     Red,
@@ -6,6 +25,8 @@ pub enum RgbBounds {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct Rgb {
     pub red: f64,
     pub green: f64,
@@ -24,9 +45,98 @@ impl Rgb {
         }
     }
 
+    /// Like `new`, but clamps channels that drifted slightly out of range.
+    pub fn new_normalized(red: f64, green: f64, blue: f64) -> Result<Rgb, RgbBounds> {
+        Rgb { red, green, blue }.normalize()
+    }
+
+    /// Clamps every channel into range. NaN stays NaN.
+    pub fn new_clamped(red: f64, green: f64, blue: f64) -> Rgb {
+        Rgb { red, green, blue }.clamp()
+    }
+
+    /// Clamps channels within `NORMALIZE_TOLERANCE` of 0..=1, and rejects
+    /// any further out like `new`.
+    pub fn normalize(&self) -> Result<Rgb, RgbBounds> {
+        Ok(Rgb {
+            red: normalize_component(self.red, 1.0).ok_or(RgbBounds::Red)?,
+            green: normalize_component(self.green, 1.0).ok_or(RgbBounds::Green)?,
+            blue: normalize_component(self.blue, 1.0).ok_or(RgbBounds::Blue)?,
+        })
+    }
+
+    pub fn clamp(&self) -> Rgb {
+        Rgb {
+            red: self.red.clamp(0.0, 1.0),
+            green: self.green.clamp(0.0, 1.0),
+            blue: self.blue.clamp(0.0, 1.0),
+        }
+    }
+
     pub fn rgb(&self) -> (f64, f64, f64) {
         (self.red, self.green, self.blue)
     }
+
+    pub fn from_rgb8(rgb: [u8; 3]) -> Rgb {
+        Rgb {
+            red: f64::from(rgb[0]) / 255.0,
+            green: f64::from(rgb[1]) / 255.0,
+            blue: f64::from(rgb[2]) / 255.0,
+        }
+    }
+
+    /// Rounds each channel to 8 bits, clamping out-of-gamut values.
+    pub fn to_rgb8(&self) -> [u8; 3] {
+        fn channel(c: f64) -> u8 {
+            Float::round(c.clamp(0.0, 1.0) * 255.0) as u8
+        }
+
+        [channel(self.red), channel(self.green), channel(self.blue)]
+    }
+
+    /// Parses `#rrggbb`, with either case of hex digit.
+    pub fn from_hex(hex: &str) -> Result<Rgb, HexError> {
+        fn channel(digits: &[u8]) -> Result<u8, HexError> {
+            let mut value = 0;
+            for digit in digits {
+                value = value * 16
+                    + match digit {
+                        b'0'..=b'9' => digit - b'0',
+                        b'a'..=b'f' => digit - b'a' + 10,
+                        b'A'..=b'F' => digit - b'A' + 10,
+                        _ => return Err(HexError::Digit),
+                    };
+            }
+            Ok(value)
+        }
+
+        let digits = match hex.as_bytes() {
+            [b'#', digits @ ..] => digits,
+            _ => return Err(HexError::Prefix),
+        };
+        if digits.len() != 6 {
+            return Err(HexError::Length);
+        }
+        Ok(Rgb::from_rgb8([
+            channel(&digits[0..2])?,
+            channel(&digits[2..4])?,
+            channel(&digits[4..6])?,
+        ]))
+    }
+
+    /// Formats as lowercase `#rrggbb`, rounding like `to_rgb8`.
+    #[cfg(feature = "alloc")]
+    pub fn to_hex(&self) -> String {
+        let [red, green, blue] = self.to_rgb8();
+        alloc::format!("#{:02x}{:02x}{:02x}", red, green, blue)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HexError {
+    Prefix,
+    Length,
+    Digit,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -36,7 +146,14 @@ pub enum HslBounds {
     Lightness,
 }
 
+/// Converting between HSLuv and HPLuv gave a saturation outside 0..=100.
+/// Holds the converted color anyway, unclamped.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct SaturationOutOfRange<T>(pub T);
+
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct Hsluv {
     pub hue: f64,
     pub saturation: f64,
@@ -59,12 +176,59 @@ impl Hsluv {
         }
     }
 
+    /// Like `new`, but wraps the hue and clamps saturation and lightness
+    /// that drifted slightly out of range.
+    pub fn new_normalized(hue: f64, saturation: f64, lightness: f64) -> Result<Hsluv, HslBounds> {
+        Hsluv {
+            hue,
+            saturation,
+            lightness,
+        }
+        .normalize()
+    }
+
+    /// Wraps the hue and clamps saturation and lightness into range. NaN
+    /// stays NaN.
+    pub fn new_clamped(hue: f64, saturation: f64, lightness: f64) -> Hsluv {
+        Hsluv {
+            hue,
+            saturation,
+            lightness,
+        }
+        .clamp()
+    }
+
+    /// Wraps the hue into 0..360 and clamps saturation and lightness within
+    /// `NORMALIZE_TOLERANCE` of 0..=100, rejecting infinite hues and anything
+    /// further out like `new`.
+    pub fn normalize(&self) -> Result<Hsluv, HslBounds> {
+        let hue = wrap_hue(self.hue);
+        if hue.is_nan() {
+            return Err(HslBounds::Hue);
+        }
+        Ok(Hsluv {
+            hue,
+            saturation: normalize_component(self.saturation, 100.0).ok_or(HslBounds::Saturation)?,
+            lightness: normalize_component(self.lightness, 100.0).ok_or(HslBounds::Lightness)?,
+        })
+    }
+
+    pub fn clamp(&self) -> Hsluv {
+        Hsluv {
+            hue: wrap_hue(self.hue),
+            saturation: self.saturation.clamp(0.0, 100.0),
+            lightness: self.lightness.clamp(0.0, 100.0),
+        }
+    }
+
     pub fn hsl(&self) -> (f64, f64, f64) {
         (self.hue, self.saturation, self.lightness)
     }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct Hpluv {
     pub hue: f64,
     pub saturation: f64,
@@ -87,6 +251,51 @@ impl Hpluv {
         }
     }
 
+    /// Like `new`, but wraps the hue and clamps saturation and lightness
+    /// that drifted slightly out of range.
+    pub fn new_normalized(hue: f64, saturation: f64, lightness: f64) -> Result<Hpluv, HslBounds> {
+        Hpluv {
+            hue,
+            saturation,
+            lightness,
+        }
+        .normalize()
+    }
+
+    /// Wraps the hue and clamps saturation and lightness into range. NaN
+    /// stays NaN.
+    pub fn new_clamped(hue: f64, saturation: f64, lightness: f64) -> Hpluv {
+        Hpluv {
+            hue,
+            saturation,
+            lightness,
+        }
+        .clamp()
+    }
+
+    /// Wraps the hue into 0..360 and clamps saturation and lightness within
+    /// `NORMALIZE_TOLERANCE` of 0..=100, rejecting infinite hues and anything
+    /// further out like `new`.
+    pub fn normalize(&self) -> Result<Hpluv, HslBounds> {
+        let hue = wrap_hue(self.hue);
+        if hue.is_nan() {
+            return Err(HslBounds::Hue);
+        }
+        Ok(Hpluv {
+            hue,
+            saturation: normalize_component(self.saturation, 100.0).ok_or(HslBounds::Saturation)?,
+            lightness: normalize_component(self.lightness, 100.0).ok_or(HslBounds::Lightness)?,
+        })
+    }
+
+    pub fn clamp(&self) -> Hpluv {
+        Hpluv {
+            hue: wrap_hue(self.hue),
+            saturation: self.saturation.clamp(0.0, 100.0),
+            lightness: self.lightness.clamp(0.0, 100.0),
+        }
+    }
+
     pub fn hsl(&self) -> (f64, f64, f64) {
         (self.hue, self.saturation, self.lightness)
     }
@@ -100,6 +309,8 @@ pub enum XyzBounds {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct Xyz {
     pub x: f64,
     pub y: f64,
@@ -107,6 +318,13 @@ pub struct Xyz {
 }
 
 impl Xyz {
+    /// The D65 white point, exactly what sRGB white converts to.
+    pub const WHITE: Xyz = Xyz {
+        x: M_INV[0].0 + M_INV[0].1 + M_INV[0].2,
+        y: M_INV[1].0 + M_INV[1].1 + M_INV[1].2,
+        z: M_INV[2].0 + M_INV[2].1 + M_INV[2].2,
+    };
+
     pub fn new(x: f64, y: f64, z: f64) -> Result<Xyz, XyzBounds> {
         if !(0.0..=1.0).contains(&x) {
             Err(XyzBounds::X)
@@ -119,6 +337,38 @@ impl Xyz {
         }
     }
 
+    /// Clamps components that drifted slightly outside black to `WHITE`;
+    /// see `normalize`.
+    pub fn new_normalized(x: f64, y: f64, z: f64) -> Result<Xyz, XyzBounds> {
+        Xyz { x, y, z }.normalize()
+    }
+
+    /// Clamps every component between black and `WHITE`. NaN stays NaN.
+    pub fn new_clamped(x: f64, y: f64, z: f64) -> Xyz {
+        Xyz { x, y, z }.clamp()
+    }
+
+    /// Clamps components within `NORMALIZE_TOLERANCE` of the range between
+    /// black and the D65 white point, `WHITE`, and rejects any further out.
+    /// Unlike `new`, which stops at 1, this accepts every sRGB color,
+    /// including white with its `z` of about 1.089.
+    pub fn normalize(&self) -> Result<Xyz, XyzBounds> {
+        Ok(Xyz {
+            x: normalize_component(self.x, Xyz::WHITE.x).ok_or(XyzBounds::X)?,
+            y: normalize_component(self.y, Xyz::WHITE.y).ok_or(XyzBounds::Y)?,
+            z: normalize_component(self.z, Xyz::WHITE.z).ok_or(XyzBounds::Z)?,
+        })
+    }
+
+    /// Clamps every component between black and `WHITE`.
+    pub fn clamp(&self) -> Xyz {
+        Xyz {
+            x: self.x.clamp(0.0, Xyz::WHITE.x),
+            y: self.y.clamp(0.0, Xyz::WHITE.y),
+            z: self.z.clamp(0.0, Xyz::WHITE.z),
+        }
+    }
+
     pub fn xyz(&self) -> (f64, f64, f64) {
         (self.x, self.y, self.z)
     }
@@ -132,6 +382,8 @@ impl Xyz {
  */
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct Luv {
     pub lightness: f64,
     pub u: f64,
@@ -145,6 +397,8 @@ impl Luv {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct Lch {
     pub lightness: f64,
     pub chroma: f64,
@@ -1,4 +1,5 @@
 use super::*;
+use proptest::prelude::*;
 
 #[derive(Debug, Clone)]
 struct ColorValues {
@@ -10,7 +11,11 @@ struct ColorValues {
     hsluv: Hsluv,
 }
 
-include!(concat!(env!("OUT_DIR"), "/snapshot-rev4.rs"));
+include!(concat!(env!("OUT_DIR"), "/snapshots.rs"));
+
+// The revision this crate implements; other snapshots only run the
+// end-to-end checks in their own modules.
+use snapshot_rev4::SNAPSHOT;
 
 const MAX_DIFF: f64 = 0.00000001;
 
@@ -122,3 +127,1507 @@ fn test_hpluv_to_lch() {
         check_eq(color, "Lch.hue", values.lch.hue, lch.hue);
     }
 }
+
+fn check_samples(samples: impl Iterator<Item = (Hsluv, Rgb)>) {
+    for (hsluv, rgb) in samples {
+        let color = "sample";
+        let expected = Rgb::from(hsluv);
+        check_eq(color, "Rgb.red", expected.red, rgb.red);
+        check_eq(color, "Rgb.green", expected.green, rgb.green);
+        check_eq(color, "Rgb.blue", expected.blue, rgb.blue);
+    }
+}
+
+#[test]
+fn test_hue_wheel() {
+    let wheel = HueWheel::new(80.0, 50.0, 12);
+    assert_eq!(wheel.len(), 12);
+    let hues = wheel.clone().map(|(hsluv, _)| hsluv.hue);
+    for (i, hue) in hues.enumerate() {
+        check_eq("wheel", "Hsluv.hue", 30.0 * i as f64, hue);
+    }
+    check_samples(wheel);
+}
+
+#[test]
+fn test_planes() {
+    let plane = HueSaturationPlane::new(60.0, 8, 5);
+    assert_eq!(plane.len(), 40);
+    let last = plane.clone().last().unwrap().0;
+    check_eq("plane", "Hsluv.hue", 315.0, last.hue);
+    check_eq("plane", "Hsluv.saturation", 100.0, last.saturation);
+    check_samples(plane);
+
+    let plane = SaturationLightnessPlane::new(210.0, 6, 11);
+    assert_eq!(plane.len(), 66);
+    let last = plane.clone().last().unwrap().0;
+    check_eq("plane", "Hsluv.saturation", 100.0, last.saturation);
+    check_eq("plane", "Hsluv.lightness", 100.0, last.lightness);
+    check_samples(plane);
+}
+
+fn pixel(pixels: &[u8], width: usize, x: usize, y: usize) -> [u8; 4] {
+    let i = (y * width + x) * 4;
+    [pixels[i], pixels[i + 1], pixels[i + 2], pixels[i + 3]]
+}
+
+#[test]
+fn test_render_hue_saturation_disk() {
+    let mark = [1, 2, 3, 4];
+    let mut pixels = [0xaa; 9 * 9 * 4];
+    render_hue_saturation_disk(&mut pixels, 9, 9, PickerSpace::Hsluv, 50.0, Some(mark));
+    assert!(!pixels.chunks(4).any(|p| p == mark));
+    assert_eq!(pixel(&pixels, 9, 0, 0), [0, 0, 0, 0]);
+    let gray = Rgb::from(Hsluv::new(0.0, 0.0, 50.0).unwrap());
+    let gray = Float::round(gray.red * 255.0) as u8;
+    assert_eq!(pixel(&pixels, 9, 4, 4), [gray, gray, gray, 255]);
+    // Hue 0 at full saturation is at the right edge.
+    let red = Rgb::from(Hsluv::new(0.0, 100.0 * 4.0 / 4.5, 50.0).unwrap());
+    assert_eq!(
+        pixel(&pixels, 9, 8, 4)[0],
+        Float::round(red.red * 255.0) as u8
+    );
+
+    render_hue_saturation_disk(&mut pixels, 9, 9, PickerSpace::Hpluv, 120.0, Some(mark));
+    assert_eq!(pixel(&pixels, 9, 4, 4), mark);
+    assert_eq!(pixel(&pixels, 9, 0, 0), [0, 0, 0, 0]);
+}
+
+#[test]
+fn test_render_lightness_strip() {
+    let mark = [1, 2, 3, 4];
+    let mut pixels = [0; 11 * 2 * 4];
+    render_lightness_strip(
+        &mut pixels,
+        11,
+        2,
+        PickerSpace::Hsluv,
+        120.0,
+        100.0,
+        Some(mark),
+    );
+    assert_eq!(pixel(&pixels, 11, 0, 1), [0, 0, 0, 255]);
+    assert_eq!(pixel(&pixels, 11, 10, 1), [255, 255, 255, 255]);
+    assert!(!pixels.chunks(4).any(|p| p == mark));
+
+    render_lightness_strip(
+        &mut pixels,
+        11,
+        2,
+        PickerSpace::Hpluv,
+        120.0,
+        300.0,
+        Some(mark),
+    );
+    assert_eq!(pixel(&pixels, 11, 5, 0), mark);
+    assert_eq!(pixel(&pixels, 11, 0, 0), [0, 0, 0, 255]);
+}
+
+#[test]
+fn test_render_luv_plane() {
+    let mark = [1, 2, 3, 4];
+    let outline = [5, 6, 7, 8];
+    let mut pixels = [0; 32 * 32 * 4];
+    render_luv_plane(&mut pixels, 32, 32, 60.0, Some(mark));
+    assert_ne!(pixel(&pixels, 32, 16, 16), mark);
+    assert_eq!(pixel(&pixels, 32, 0, 0), mark);
+    assert!(pixels.chunks(4).filter(|&p| p != mark).count() > 100);
+
+    render_gamut_polygon(&mut pixels, 32, 32, 60.0, outline);
+    assert_ne!(pixel(&pixels, 32, 16, 16), outline);
+    assert!(pixels.chunks(4).any(|p| p == outline));
+}
+
+#[test]
+fn test_render_empty() {
+    for &(width, height) in [(0, 0), (4, 0), (0, 4)].iter() {
+        let space = PickerSpace::Hsluv;
+        render_hue_saturation_disk(&mut [], width, height, space, 50.0, None);
+        render_lightness_strip(&mut [], width, height, space, 120.0, 100.0, None);
+        render_luv_plane(&mut [], width, height, 50.0, None);
+        render_gamut_polygon(&mut [], width, height, 50.0, [0; 4]);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_render_buffer_mismatch() {
+    render_luv_plane(&mut [0; 15], 2, 2, 50.0, None);
+}
+
+#[test]
+fn test_batch() {
+    for chunk in SNAPSHOT.chunks(64) {
+        let mut rgb = [Rgb {
+            red: 0.0,
+            green: 0.0,
+            blue: 0.0,
+        }; 64];
+        let mut hsluv = [Hsluv {
+            hue: 0.0,
+            saturation: 0.0,
+            lightness: 0.0,
+        }; 64];
+        let mut hpluv = [Hpluv {
+            hue: 0.0,
+            saturation: 0.0,
+            lightness: 0.0,
+        }; 64];
+        let (rgb, hsluv, hpluv) = (
+            &mut rgb[..chunk.len()],
+            &mut hsluv[..chunk.len()],
+            &mut hpluv[..chunk.len()],
+        );
+
+        for (i, (_, values)) in chunk.iter().enumerate() {
+            hsluv[i] = values.hsluv;
+            hpluv[i] = values.hpluv;
+        }
+        hsluv_to_rgb_slice(hsluv, rgb);
+        for ((color, values), rgb) in chunk.iter().zip(rgb.iter()) {
+            check_eq(color, "Rgb.red", values.rgb.red, rgb.red);
+            check_eq(color, "Rgb.green", values.rgb.green, rgb.green);
+            check_eq(color, "Rgb.blue", values.rgb.blue, rgb.blue);
+        }
+        hpluv_to_rgb_slice(hpluv, rgb);
+        for ((color, values), rgb) in chunk.iter().zip(rgb.iter()) {
+            check_eq(color, "Rgb.red", values.rgb.red, rgb.red);
+            check_eq(color, "Rgb.green", values.rgb.green, rgb.green);
+            check_eq(color, "Rgb.blue", values.rgb.blue, rgb.blue);
+        }
+
+        for (i, (_, values)) in chunk.iter().enumerate() {
+            rgb[i] = values.rgb;
+        }
+        rgb_to_hsluv_slice(rgb, hsluv);
+        rgb_to_hpluv_slice(rgb, hpluv);
+        for (((color, values), hsluv), hpluv) in chunk.iter().zip(hsluv.iter()).zip(hpluv.iter()) {
+            check_eq(color, "Hsluv.hue", values.hsluv.hue, hsluv.hue);
+            check_eq(
+                color,
+                "Hsluv.saturation",
+                values.hsluv.saturation,
+                hsluv.saturation,
+            );
+            check_eq(
+                color,
+                "Hsluv.lightness",
+                values.hsluv.lightness,
+                hsluv.lightness,
+            );
+            check_eq(color, "Hpluv.hue", values.hpluv.hue, hpluv.hue);
+            check_eq(
+                color,
+                "Hpluv.saturation",
+                values.hpluv.saturation,
+                hpluv.saturation,
+            );
+            check_eq(
+                color,
+                "Hpluv.lightness",
+                values.hpluv.lightness,
+                hpluv.lightness,
+            );
+        }
+    }
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn test_image() {
+    let image = ::image::RgbaImage::from_fn(16, 16, |x, y| {
+        ::image::Rgba([(x * 16) as u8, (y * 16) as u8, 0x80, (x + y) as u8])
+    });
+
+    let planes = HsluvImage::from_image(&image);
+    assert_eq!(planes.to_image::<::image::Rgba<u8>>(), image);
+
+    let mut lighter = image.clone();
+    map_hsluv(&mut lighter, |c| Hsluv {
+        lightness: Float::min(c.lightness + 10.0, 100.0),
+        ..c
+    });
+    for (before, after) in image.pixels().zip(lighter.pixels()) {
+        assert_eq!(before[3], after[3]);
+        let (before, after) = (
+            Hsluv::from(before.to_color()),
+            Hsluv::from(after.to_color()),
+        );
+        assert!(after.lightness > Float::min(before.lightness, 90.0) + 9.0);
+    }
+
+    let mut same = ::image::RgbImage::from_fn(300, 2, |x, y| {
+        ::image::Rgb([x as u8, (y * 100) as u8, 255 - x as u8])
+    });
+    let expected = same.clone();
+    map_hsluv(&mut same, |c| c);
+    assert_eq!(same, expected);
+
+    let mut samples = vec![0x40; 2 * 2 * 3];
+    samples.extend_from_slice(&[1, 2, 3, 4, 5]);
+    let mut oversized =
+        ::image::ImageBuffer::<::image::Rgb<u8>, _>::from_raw(2, 2, samples).unwrap();
+    map_hsluv(&mut oversized, |c| Hsluv {
+        lightness: 100.0,
+        ..c
+    });
+    let samples = oversized.into_raw();
+    assert_eq!(samples[..12], [255; 12]);
+    assert_eq!(samples[12..], [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_adjustments() {
+    let color = Hsluv::new(350.0, 60.0, 40.0).unwrap();
+    check_eq(
+        "adjust",
+        "Hsluv.lightness",
+        50.0,
+        color.lighten(10.0).lightness,
+    );
+    check_eq(
+        "adjust",
+        "Hsluv.lightness",
+        100.0,
+        color.lighten(80.0).lightness,
+    );
+    check_eq(
+        "adjust",
+        "Hsluv.lightness",
+        0.0,
+        color.darken(50.0).lightness,
+    );
+    check_eq(
+        "adjust",
+        "Hsluv.lightness",
+        70.0,
+        color.lighten_by(0.5).lightness,
+    );
+    check_eq(
+        "adjust",
+        "Hsluv.lightness",
+        20.0,
+        color.darken_by(0.5).lightness,
+    );
+    check_eq(
+        "adjust",
+        "Hsluv.saturation",
+        80.0,
+        color.saturate_by(0.5).saturation,
+    );
+    check_eq(
+        "adjust",
+        "Hsluv.saturation",
+        30.0,
+        color.desaturate_by(0.5).saturation,
+    );
+    check_eq(
+        "adjust",
+        "Hsluv.saturation",
+        100.0,
+        color.saturate(50.0).saturation,
+    );
+    check_eq("adjust", "Hsluv.hue", 20.0, color.rotate_hue(30.0).hue);
+    check_eq("adjust", "Hsluv.hue", 170.0, color.complement().hue);
+    check_eq("adjust", "Hsluv.hue", 350.0, color.rotate_hue(-720.0).hue);
+    check_eq("adjust", "Hsluv.lightness", 60.0, color.invert().lightness);
+
+    let color = Hpluv::new(10.0, 60.0, 40.0).unwrap();
+    check_eq("adjust", "Hpluv.hue", 310.0, color.rotate_hue(-60.0).hue);
+    check_eq(
+        "adjust",
+        "Hpluv.saturation",
+        0.0,
+        color.desaturate(70.0).saturation,
+    );
+
+    assert!((0.0..360.0).contains(&wrap_hue(-0.00000000000001)));
+
+    for (color, values) in SNAPSHOT.iter() {
+        for lch in [
+            values.lch.saturate(500.0),
+            values.lch.saturate_by(1.0),
+            values.lch.lighten(15.0).rotate_hue(77.0),
+            values.lch.invert(),
+        ] {
+            let hsluv = Hsluv::from(lch);
+            assert!(
+                hsluv.saturation <= 100.0 + MAX_DIFF,
+                "{} {:?}: out of gamut",
+                color,
+                lch
+            );
+        }
+        if (0.01..99.99).contains(&values.lch.lightness) {
+            let max = values.lch.saturate_by(1.0);
+            check_eq(
+                color,
+                "Hsluv.saturation",
+                100.0,
+                Hsluv::from(max).saturation,
+            );
+        }
+    }
+}
+
+#[test]
+fn test_wrap_hue() {
+    check_eq("wrap", "hue", 0.0, wrap_hue(360.0));
+    check_eq("wrap", "hue", 10.0, wrap_hue(370.0));
+    check_eq("wrap", "hue", 350.0, wrap_hue(-10.0));
+    check_eq("wrap", "hue", 0.0, wrap_hue(-720.0));
+    assert!(wrap_hue(f64::NAN).is_nan());
+    assert!(wrap_hue(f64::INFINITY).is_nan());
+
+    for (color, values) in SNAPSHOT.iter() {
+        for offset in [-720.0, -360.0, 360.0, 1080.0] {
+            let hsluv = Hsluv {
+                hue: values.hsluv.hue + offset,
+                ..values.hsluv
+            };
+            let lch = Lch::from(hsluv);
+            check_eq(color, "Lch.hue", values.lch.hue, lch.hue);
+            check_eq(color, "Lch.chroma", values.lch.chroma, lch.chroma);
+            let rgb = Rgb::from(hsluv);
+            check_eq(color, "Rgb.red", values.rgb.red, rgb.red);
+            check_eq(color, "Rgb.green", values.rgb.green, rgb.green);
+            check_eq(color, "Rgb.blue", values.rgb.blue, rgb.blue);
+
+            let hpluv = Hpluv {
+                hue: values.hpluv.hue + offset,
+                ..values.hpluv
+            };
+            let lch = Lch::from(hpluv);
+            check_eq(color, "Lch.hue", values.lch.hue, lch.hue);
+            check_eq(color, "Lch.chroma", values.lch.chroma, lch.chroma);
+
+            let lch = Lch {
+                hue: values.lch.hue + offset,
+                ..values.lch
+            };
+            check_eq(color, "Hsluv.hue", values.hsluv.hue, Hsluv::from(lch).hue);
+            check_eq(color, "Hpluv.hue", values.hpluv.hue, Hpluv::from(lch).hue);
+            let luv = Luv::from(lch);
+            check_eq(color, "Luv.u", values.luv.u, luv.u);
+            check_eq(color, "Luv.v", values.luv.v, luv.v);
+        }
+    }
+}
+
+#[test]
+fn test_checked_from() {
+    fn check<T, U>(valid: T, with: fn(T, f64) -> T)
+    where
+        T: Finite + Copy + core::fmt::Debug,
+        U: From<T> + Finite,
+    {
+        assert!(U::checked_from(valid).is_ok(), "{:?}", valid);
+        for (bad, error) in [
+            (f64::NAN, NonFinite::Nan),
+            (f64::INFINITY, NonFinite::Infinite),
+            (f64::NEG_INFINITY, NonFinite::Infinite),
+        ] {
+            let value = with(valid, bad);
+            assert_eq!(U::checked_from(value).err(), Some(error), "{:?}", value);
+        }
+    }
+
+    let (rgb, xyz, luv, lch, hsluv, hpluv) = {
+        let values = &SNAPSHOT[SNAPSHOT.len() / 2].1;
+        (
+            values.rgb,
+            values.xyz,
+            values.luv,
+            values.lch,
+            values.hsluv,
+            values.hpluv,
+        )
+    };
+
+    check::<_, Rgb>(xyz, |c, x| Xyz { x, ..c });
+    check::<_, Xyz>(rgb, |c, blue| Rgb { blue, ..c });
+    check::<_, Luv>(xyz, |c, z| Xyz { z, ..c });
+    check::<_, Xyz>(luv, |c, u| Luv { u, ..c });
+    check::<_, Lch>(luv, |c, v| Luv { v, ..c });
+    check::<_, Luv>(lch, |c, hue| Lch { hue, ..c });
+    check::<_, Hsluv>(lch, |c, chroma| Lch { chroma, ..c });
+    check::<_, Hpluv>(lch, |c, chroma| Lch { chroma, ..c });
+    check::<_, Lch>(hsluv, |c, hue| Hsluv { hue, ..c });
+    check::<_, Lch>(hpluv, |c, saturation| Hpluv { saturation, ..c });
+    check::<_, Rgb>(hsluv, |c, saturation| Hsluv { saturation, ..c });
+    check::<_, Rgb>(hpluv, |c, hue| Hpluv { hue, ..c });
+    check::<_, Hsluv>(rgb, |c, red| Rgb { red, ..c });
+    check::<_, Hpluv>(rgb, |c, green| Rgb { green, ..c });
+
+    let black = Xyz {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    };
+    assert_eq!(Luv::checked_from(black).ok(), Some(Luv::from(black)));
+    let rgb: Result<Rgb, _> = Hsluv::new(120.0, 50.0, 50.0).unwrap().checked_into();
+    assert!(rgb.is_ok());
+}
+
+#[test]
+fn test_from_color() {
+    const SPACES: [Space; 6] = [
+        Space::Rgb,
+        Space::Xyz,
+        Space::Luv,
+        Space::Lch,
+        Space::Hsluv,
+        Space::Hpluv,
+    ];
+
+    fn snapshot_color(values: &ColorValues, space: Space) -> AnyColor {
+        match space {
+            Space::Rgb => AnyColor::Rgb(values.rgb),
+            Space::Xyz => AnyColor::Xyz(values.xyz),
+            Space::Luv => AnyColor::Luv(values.luv),
+            Space::Lch => AnyColor::Lch(values.lch),
+            Space::Hsluv => AnyColor::Hsluv(values.hsluv),
+            Space::Hpluv => AnyColor::Hpluv(values.hpluv),
+        }
+    }
+
+    fn components(color: AnyColor) -> (f64, f64, f64) {
+        match color {
+            AnyColor::Rgb(rgb) => rgb.rgb(),
+            AnyColor::Xyz(xyz) => xyz.xyz(),
+            AnyColor::Luv(luv) => luv.luv(),
+            AnyColor::Lch(lch) => lch.lch(),
+            AnyColor::Hsluv(hsluv) => hsluv.hsl(),
+            AnyColor::Hpluv(hpluv) => hpluv.hsl(),
+        }
+    }
+
+    for (color, values) in SNAPSHOT.iter() {
+        for &from in SPACES.iter() {
+            for &to in SPACES.iter() {
+                let expected = components(snapshot_color(values, to));
+                let converted = snapshot_color(values, from).convert(to);
+                assert_eq!(converted.space(), to);
+                let actual = components(converted);
+                for (expected, actual) in [
+                    (expected.0, actual.0),
+                    (expected.1, actual.1),
+                    (expected.2, actual.2),
+                ] {
+                    if Float::abs(expected - actual) >= MAX_DIFF {
+                        panic!(
+                            "{} {:?} -> {:?}: expected {:?}, got {:?}",
+                            color, from, to, expected, actual
+                        )
+                    }
+                }
+            }
+        }
+    }
+
+    // `FromColor` takes the same steps as `AnyColor::convert`, so it gives
+    // exactly the same results.
+    fn check_route<T: ColorSpace, U: ColorSpace>(color: &str, from: T) {
+        let expected = U::from_any(from.into_any().convert(U::SPACE)).unwrap();
+        assert_eq!(
+            U::from_color(from).components(),
+            expected.components(),
+            "{} {:?} -> {:?}",
+            color,
+            T::SPACE,
+            U::SPACE
+        );
+    }
+
+    macro_rules! check_routes {
+        ($color:expr, $values:expr, $($from:ident: $t:ty),*) => {
+            $(
+                check_route::<$t, Rgb>($color, $values.$from);
+                check_route::<$t, Xyz>($color, $values.$from);
+                check_route::<$t, Luv>($color, $values.$from);
+                check_route::<$t, Lch>($color, $values.$from);
+                check_route::<$t, Hsluv>($color, $values.$from);
+                check_route::<$t, Hpluv>($color, $values.$from);
+            )*
+        };
+    }
+
+    for (color, values) in SNAPSHOT.iter() {
+        check_routes!(
+            color,
+            values,
+            rgb: Rgb,
+            xyz: Xyz,
+            luv: Luv,
+            lch: Lch,
+            hsluv: Hsluv,
+            hpluv: Hpluv
+        );
+    }
+
+    let hpluv = Hpluv {
+        hue: 400.0,
+        saturation: 150.0,
+        lightness: 40.0,
+    };
+    // The same space is left alone, even out of range.
+    assert_eq!(Hpluv::from_color(hpluv), hpluv);
+    let luv: Luv = hpluv.into_color();
+    assert_eq!(luv, Luv::from(Lch::from(hpluv)));
+    let hsluv: Hsluv = hpluv.into_color();
+    assert_eq!(hsluv, Hsluv::from(Lch::from(hpluv)));
+    let xyz = Xyz::from_color(hsluv);
+    assert_eq!(xyz, Xyz::from(Luv::from(Lch::from(hsluv))));
+    assert_eq!(Rgb::from_color(xyz), Rgb::from(xyz));
+    assert_eq!(AnyColor::from(xyz).convert(Space::Xyz), AnyColor::Xyz(xyz));
+    assert_eq!(Hpluv::from_any(AnyColor::Xyz(xyz)), None);
+}
+
+#[test]
+fn test_hsluv_hpluv() {
+    for (color, values) in SNAPSHOT.iter() {
+        let hpluv = Hpluv::from(values.hsluv);
+        check_eq(color, "Hpluv.hue", values.hpluv.hue, hpluv.hue);
+        check_eq(
+            color,
+            "Hpluv.saturation",
+            values.hpluv.saturation,
+            hpluv.saturation,
+        );
+        check_eq(
+            color,
+            "Hpluv.lightness",
+            values.hpluv.lightness,
+            hpluv.lightness,
+        );
+        match Hpluv::try_from_hsluv(values.hsluv) {
+            Ok(checked) => {
+                assert_eq!(checked, hpluv);
+                assert!(values.hpluv.saturation <= 100.00000001, "{}", color);
+            }
+            Err(SaturationOutOfRange(checked)) => {
+                assert_eq!(checked, hpluv);
+                assert!(values.hpluv.saturation > 100.0, "{}", color);
+            }
+        }
+
+        let hsluv = match Hsluv::try_from(values.hpluv) {
+            Ok(hsluv) => hsluv,
+            Err(err) => panic!("{}: {:?}", color, err),
+        };
+        check_eq(color, "Hsluv.hue", values.hsluv.hue, hsluv.hue);
+        check_eq(
+            color,
+            "Hsluv.saturation",
+            values.hsluv.saturation,
+            hsluv.saturation,
+        );
+        check_eq(
+            color,
+            "Hsluv.lightness",
+            values.hsluv.lightness,
+            hsluv.lightness,
+        );
+    }
+
+    // The snapshot's most chromatic green, in HPLuv.
+    let (_, green) = SNAPSHOT.iter().find(|(c, _)| c == &"#11ee00").unwrap();
+    assert!(Hpluv::try_from_hsluv(green.hsluv).is_err());
+    for lightness in (1..100).step_by(7) {
+        let pastel = Hpluv {
+            hue: 0.0,
+            saturation: 100.0,
+            lightness: f64::from(lightness),
+        };
+        assert!(Hsluv::try_from(pastel).is_ok());
+        let beyond = Hpluv {
+            saturation: 400.0,
+            ..pastel
+        };
+        match Hsluv::try_from(beyond) {
+            Err(SaturationOutOfRange(hsluv)) => assert!(hsluv.saturation > 100.0),
+            Ok(hsluv) => panic!("{:?} in gamut as {:?}", beyond, hsluv),
+        }
+    }
+}
+
+#[test]
+fn test_normalize() {
+    assert!(Hsluv::new(360.5, 50.0, 50.0).is_err());
+    let hsluv = Hsluv::new_normalized(360.5, 100.0000001, -0.00001).unwrap();
+    assert_eq!(hsluv.hsl(), (0.5, 100.0, 0.0));
+    assert_eq!(
+        Hsluv::new(hsluv.hue, hsluv.saturation, hsluv.lightness),
+        Ok(hsluv)
+    );
+    let hpluv = Hpluv::new_normalized(-30.0, 0.0, 100.00009).unwrap();
+    assert_eq!(hpluv.hsl(), (330.0, 0.0, 100.0));
+    for (hsl, bounds) in [
+        ((f64::INFINITY, 50.0, 50.0), HslBounds::Hue),
+        ((f64::NAN, 50.0, 50.0), HslBounds::Hue),
+        ((10.0, 100.001, 50.0), HslBounds::Saturation),
+        ((10.0, f64::NAN, 50.0), HslBounds::Saturation),
+        ((10.0, 50.0, -0.001), HslBounds::Lightness),
+    ] {
+        assert_eq!(Hsluv::new_normalized(hsl.0, hsl.1, hsl.2), Err(bounds));
+        assert_eq!(Hpluv::new_normalized(hsl.0, hsl.1, hsl.2), Err(bounds));
+    }
+    assert_eq!(
+        Hsluv::new_clamped(-720.0, 308.2, -5.0).hsl(),
+        (0.0, 100.0, 0.0)
+    );
+    assert_eq!(
+        Hpluv::new_clamped(725.0, 308.2, 101.0).hsl(),
+        (5.0, 100.0, 100.0)
+    );
+
+    let rgb = Rgb::new_normalized(1.00000002, -0.00000001, 0.5).unwrap();
+    assert_eq!(rgb.rgb(), (1.0, 0.0, 0.5));
+    assert_eq!(Rgb::new_normalized(1.0, 0.5, 1.00001), Err(RgbBounds::Blue));
+    assert_eq!(Rgb::new_normalized(f64::NAN, 0.5, 0.5), Err(RgbBounds::Red));
+    assert_eq!(Rgb::new_clamped(-0.5, 0.5, 1.5).rgb(), (0.0, 0.5, 1.0));
+    let xyz = Xyz::new_normalized(0.5, 1.0000001, 0.0).unwrap();
+    assert_eq!(xyz.xyz(), (0.5, Xyz::WHITE.y, 0.0));
+    assert_eq!(Xyz::new_normalized(0.5, 0.5, -0.1), Err(XyzBounds::Z));
+    assert_eq!(
+        Xyz::new_clamped(0.5, 2.0, -0.1).xyz(),
+        (0.5, Xyz::WHITE.y, 0.0)
+    );
+    let white = Xyz::new_normalized(0.9504559, 0.99999999, 1.0890577).unwrap();
+    assert_eq!(white.xyz(), (0.9504559, 0.99999999, 1.0890577));
+    assert_eq!(
+        Xyz::new_normalized(0.9504564, 1.0000005, 1.0890582),
+        Ok(Xyz::WHITE)
+    );
+    assert_eq!(Xyz::new_normalized(0.96, 0.5, 0.5), Err(XyzBounds::X));
+    assert_eq!(Xyz::new_clamped(2.0, 2.0, 2.0), Xyz::WHITE);
+    assert_eq!(Xyz::WHITE.clamp(), Xyz::WHITE);
+    let white = Xyz::from(Rgb::from_rgb8([255; 3]));
+    assert_eq!(white, Xyz::WHITE);
+    assert_eq!(white.clamp(), white);
+    assert_eq!(white.normalize(), Ok(white));
+
+    // Round trips drift out of range by far less than the tolerance.
+    for (color, values) in SNAPSHOT.iter() {
+        let xyz = Xyz::from(values.rgb).normalize().unwrap();
+        assert_eq!(xyz.clamp(), xyz, "{}", color);
+        check_eq(color, "Xyz.x", values.xyz.x, xyz.x);
+        check_eq(color, "Xyz.y", values.xyz.y, xyz.y);
+        check_eq(color, "Xyz.z", values.xyz.z, xyz.z);
+        let rgb = Rgb::from(Hsluv::from(values.rgb)).normalize().unwrap();
+        assert_eq!(Rgb::new(rgb.red, rgb.green, rgb.blue), Ok(rgb), "{}", color);
+        let hsluv = Hsluv::from(Lch::from(values.hsluv)).normalize().unwrap();
+        assert_eq!(
+            Hsluv::new(hsluv.hue, hsluv.saturation, hsluv.lightness),
+            Ok(hsluv),
+            "{}",
+            color
+        );
+    }
+}
+
+#[test]
+fn test_cusp_for_hue() {
+    let (_, red) = SNAPSHOT.iter().find(|(c, _)| c == &"#ff0000").unwrap();
+    let cusp = cusp_for_hue(red.lch.hue);
+    assert!(Float::abs(red.lch.lightness - cusp.lightness) < 0.000001);
+    assert!(Float::abs(red.lch.chroma - cusp.chroma) < 0.000001);
+
+    for hue in (0..360).step_by(15) {
+        let cusp = cusp_for_hue(f64::from(hue));
+        for lightness in 1..100 {
+            let chroma = max_chroma_for_lightness_hue(f64::from(lightness), f64::from(hue));
+            assert!(
+                chroma <= cusp.chroma + MAX_DIFF,
+                "hue {}: chroma {} at lightness {} beyond cusp {:?}",
+                hue,
+                chroma,
+                lightness,
+                cusp
+            );
+        }
+    }
+    check_eq("cusp", "Lch.hue", 10.0, cusp_for_hue(370.0).hue);
+    for &hue in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY].iter() {
+        let cusp = cusp_for_hue(hue);
+        assert!(
+            cusp.lightness.is_nan() && cusp.chroma.is_nan(),
+            "{:?}",
+            cusp
+        );
+    }
+}
+
+#[test]
+fn test_lightness_range_for_chroma_hue() {
+    for (color, values) in SNAPSHOT.iter().step_by(7) {
+        let lch = values.lch;
+        if lch.chroma < 0.001 {
+            continue;
+        }
+        let (low, high) = lightness_range_for_chroma_hue(lch.chroma, lch.hue).unwrap();
+        assert!(
+            low <= lch.lightness + 0.000001 && lch.lightness <= high + 0.000001,
+            "{}: {} not in {}..={}",
+            color,
+            lch.lightness,
+            low,
+            high
+        );
+        for lightness in [low, high] {
+            let max = max_chroma_for_lightness_hue(lightness, lch.hue);
+            assert!(
+                Float::abs(max - lch.chroma) < 0.000001,
+                "{}: max chroma {} at {}",
+                color,
+                max,
+                lightness
+            );
+        }
+    }
+    assert_eq!(
+        lightness_range_for_chroma_hue(0.0, 40.0),
+        Some((0.0, 100.0))
+    );
+    assert_eq!(lightness_range_for_chroma_hue(500.0, 40.0), None);
+    for &chroma in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY].iter() {
+        assert_eq!(lightness_range_for_chroma_hue(chroma, 40.0), None);
+    }
+    for &hue in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY].iter() {
+        assert_eq!(lightness_range_for_chroma_hue(0.0, hue), None);
+        assert_eq!(lightness_range_for_chroma_hue(20.0, hue), None);
+    }
+}
+
+#[test]
+fn test_const_fns() {
+    const TEAL: Rgb = Rgb::from_hsluv_const(Hsluv {
+        hue: 210.0,
+        saturation: 80.0,
+        lightness: 50.0,
+    });
+    const TEAL_HSLUV: Hsluv = Hsluv::from_rgb_const(TEAL);
+    let expected = Rgb::from(Hsluv::new(210.0, 80.0, 50.0).unwrap());
+    check_eq("teal", "Rgb.red", expected.red, TEAL.red);
+    check_eq("teal", "Rgb.green", expected.green, TEAL.green);
+    check_eq("teal", "Rgb.blue", expected.blue, TEAL.blue);
+    check_eq("teal", "Hsluv.hue", 210.0, TEAL_HSLUV.hue);
+    check_eq("teal", "Hsluv.saturation", 80.0, TEAL_HSLUV.saturation);
+    check_eq("teal", "Hsluv.lightness", 50.0, TEAL_HSLUV.lightness);
+
+    for (color, values) in SNAPSHOT.iter() {
+        let rgb = Rgb::from_hsluv_const(values.hsluv);
+        check_eq(color, "Rgb.red", values.rgb.red, rgb.red);
+        check_eq(color, "Rgb.green", values.rgb.green, rgb.green);
+        check_eq(color, "Rgb.blue", values.rgb.blue, rgb.blue);
+
+        let rgb = Rgb::from_hpluv_const(values.hpluv);
+        check_eq(color, "Rgb.red", values.rgb.red, rgb.red);
+        check_eq(color, "Rgb.green", values.rgb.green, rgb.green);
+        check_eq(color, "Rgb.blue", values.rgb.blue, rgb.blue);
+
+        let hsluv = Hsluv::from_rgb_const(values.rgb);
+        check_eq(color, "Hsluv.hue", values.hsluv.hue, hsluv.hue);
+        check_eq(
+            color,
+            "Hsluv.saturation",
+            values.hsluv.saturation,
+            hsluv.saturation,
+        );
+        check_eq(
+            color,
+            "Hsluv.lightness",
+            values.hsluv.lightness,
+            hsluv.lightness,
+        );
+
+        let hpluv = Hpluv::from_rgb_const(values.rgb);
+        check_eq(color, "Hpluv.hue", values.hpluv.hue, hpluv.hue);
+        check_eq(
+            color,
+            "Hpluv.saturation",
+            values.hpluv.saturation,
+            hpluv.saturation,
+        );
+        check_eq(
+            color,
+            "Hpluv.lightness",
+            values.hpluv.lightness,
+            hpluv.lightness,
+        );
+    }
+}
+
+#[test]
+fn test_hex() {
+    assert_eq!(
+        Rgb::from_hex("#11EE00"),
+        Ok(Rgb::from_rgb8([0x11, 0xee, 0]))
+    );
+    assert_eq!(Rgb::from_hex("11ee00"), Err(HexError::Prefix));
+    assert_eq!(Rgb::from_hex("#11ee0"), Err(HexError::Length));
+    assert_eq!(Rgb::from_hex("#11ee0g"), Err(HexError::Digit));
+    assert_eq!(Rgb::from_hex("#11ee\u{e9}"), Err(HexError::Digit));
+    assert_eq!(
+        Rgb {
+            red: -0.1,
+            green: 0.5,
+            blue: 1.2
+        }
+        .to_rgb8(),
+        [0, 128, 255]
+    );
+}
+
+#[test]
+fn test_hsluv_to_rgb8_fixed() {
+    for (color, values) in SNAPSHOT.iter() {
+        let hue = Float::round(values.hsluv.hue / 360.0 * 65536.0) as u32 as u16;
+        let saturation =
+            Float::round(Float::min(values.hsluv.saturation, 100.0) / 100.0 * 65535.0) as u16;
+        let lightness = Float::round(values.hsluv.lightness / 100.0 * 65535.0) as u16;
+        let expected = Rgb::from(Hsluv {
+            hue: f64::from(hue) / 65536.0 * 360.0,
+            saturation: f64::from(saturation) / 65535.0 * 100.0,
+            lightness: f64::from(lightness) / 65535.0 * 100.0,
+        })
+        .to_rgb8();
+        let actual = hsluv_to_rgb8_fixed(hue, saturation, lightness);
+        for (expected, actual) in expected.iter().zip(actual.iter()) {
+            let error = (i16::from(*expected) - i16::from(*actual)).abs();
+            assert!(
+                error <= 1,
+                "{}: expected {:?}, got {:?}",
+                color,
+                expected,
+                actual
+            );
+        }
+    }
+}
+
+#[test]
+fn test_rgb8_batch() {
+    for chunk in SNAPSHOT.chunks(64) {
+        let mut rgb8 = [[0; 3]; 64];
+        let mut hsluv = [Hsluv {
+            hue: 0.0,
+            saturation: 0.0,
+            lightness: 0.0,
+        }; 64];
+        let mut hpluv = [Hpluv {
+            hue: 0.0,
+            saturation: 0.0,
+            lightness: 0.0,
+        }; 64];
+        let (rgb8, hsluv, hpluv) = (
+            &mut rgb8[..chunk.len()],
+            &mut hsluv[..chunk.len()],
+            &mut hpluv[..chunk.len()],
+        );
+
+        for (i, (_, values)) in chunk.iter().enumerate() {
+            rgb8[i] = values.rgb.to_rgb8();
+        }
+        rgb8_to_hsluv_slice(rgb8, hsluv);
+        rgb8_to_hpluv_slice(rgb8, hpluv);
+        for (((color, values), hsluv), hpluv) in chunk.iter().zip(hsluv.iter()).zip(hpluv.iter()) {
+            check_eq(color, "Hsluv.hue", values.hsluv.hue, hsluv.hue);
+            check_eq(
+                color,
+                "Hsluv.saturation",
+                values.hsluv.saturation,
+                hsluv.saturation,
+            );
+            check_eq(
+                color,
+                "Hsluv.lightness",
+                values.hsluv.lightness,
+                hsluv.lightness,
+            );
+            check_eq(color, "Hpluv.hue", values.hpluv.hue, hpluv.hue);
+            check_eq(
+                color,
+                "Hpluv.saturation",
+                values.hpluv.saturation,
+                hpluv.saturation,
+            );
+        }
+    }
+}
+
+#[cfg(feature = "lut")]
+#[test]
+fn test_lut() {
+    for i in 0..=1_000_000 {
+        let x = f64::from(i) / 1_000_000.0;
+        let errors = [
+            lut::TO_LINEAR.get(x).map(|y| y - Float::powf(x, 2.4)),
+            lut::FROM_LINEAR
+                .get(x)
+                .map(|y| y - Float::powf(x, 1.0 / 2.4)),
+            lut::CBRT.get(x).map(|y| y - Float::cbrt(x)),
+        ];
+        for error in errors.iter().flatten() {
+            assert!(Float::abs(*error) < 1e-13, "{}: error {}", x, error);
+        }
+    }
+    for (i, y) in lut::RGB8_TO_LINEAR.iter().enumerate() {
+        let c = i as f64 / 255.0;
+        let exact = if c > 0.04045 {
+            Float::powf((c + 0.055) / 1.055, 2.4)
+        } else {
+            c / 12.92
+        };
+        assert!(Float::abs(y - exact) < 1e-15, "{}: {} != {}", i, y, exact);
+    }
+}
+
+fn check_round_trip(expected: Rgb, actual: Rgb) -> Result<(), TestCaseError> {
+    for (expected, actual) in [
+        (expected.red, actual.red),
+        (expected.green, actual.green),
+        (expected.blue, actual.blue),
+    ] {
+        prop_assert!(
+            Float::abs(expected - actual) <= ROUND_TRIP_ERROR,
+            "expected {:?}, got {:?}",
+            expected,
+            actual
+        );
+    }
+    Ok(())
+}
+
+// Channels biased towards the edges of the gamut, where the black, white and
+// gray special cases live.
+fn channel() -> impl Strategy<Value = f64> {
+    prop_oneof![
+        Just(0.0),
+        Just(1.0),
+        0.0..1e-6,
+        (1.0 - 1e-6)..1.0,
+        0.0..=1.0,
+    ]
+}
+
+fn rgb() -> impl Strategy<Value = Rgb> {
+    (channel(), channel(), channel()).prop_map(|(red, green, blue)| Rgb { red, green, blue })
+}
+
+proptest! {
+    #[test]
+    fn test_hsluv_round_trip(rgb in rgb()) {
+        check_round_trip(rgb, Rgb::from(Hsluv::from(rgb)))?;
+    }
+
+    #[test]
+    fn test_hpluv_round_trip(rgb in rgb()) {
+        check_round_trip(rgb, Rgb::from(Hpluv::from(rgb)))?;
+    }
+
+    #[test]
+    fn test_y_to_lightness_monotonic(a in 0.0..=1.0, b in 0.0..=1.0) {
+        let (low, high) = if a <= b { (a, b) } else { (b, a) };
+        prop_assert!(y_to_lightness(low) <= y_to_lightness(high));
+    }
+
+    #[test]
+    fn test_lightness_to_y_inverse(lightness in 0.0..=100.0) {
+        let round_trip = y_to_lightness(lightness_to_y(lightness));
+        prop_assert!(Float::abs(lightness - round_trip) < 1e-10);
+    }
+}
+
+#[test]
+fn test_lightness_branch_continuity() {
+    // Step across each branch point a few floats at a time; the two sides
+    // must meet, and the curve must not step backwards where they do.
+    fn steps(x: f64) -> impl Iterator<Item = f64> {
+        (-4..=4).map(move |i: i64| f64::from_bits((x.to_bits() as i64 + i) as u64))
+    }
+
+    assert!(Float::abs(EPSILON * KAPPA - (116.0 * Float::cbrt(EPSILON) - 16.0)) < 1e-12);
+    let mut previous = f64::MIN;
+    for y in steps(EPSILON) {
+        let lightness = y_to_lightness(y);
+        assert!(
+            lightness >= previous,
+            "y_to_lightness({}) = {} < {}",
+            y,
+            lightness,
+            previous
+        );
+        previous = lightness;
+    }
+
+    assert!(Float::abs(8.0 / KAPPA - Float::powi((8.0 + 16.0) / 116.0, 3)) < 1e-15);
+    let mut previous = f64::MIN;
+    for lightness in steps(8.0) {
+        let y = lightness_to_y(lightness);
+        assert!(
+            y >= previous,
+            "lightness_to_y({}) = {} < {}",
+            lightness,
+            y,
+            previous
+        );
+        previous = y;
+    }
+}
+
+fn check_top_level_functions(snapshot: &[(&str, ColorValues)]) {
+    for (color, values) in snapshot.iter() {
+        let (red, green, blue) = hsluv_to_rgb(
+            values.hsluv.hue,
+            values.hsluv.saturation,
+            values.hsluv.lightness,
+        );
+        check_eq(color, "hsluv_to_rgb red", values.rgb.red, red);
+        check_eq(color, "hsluv_to_rgb green", values.rgb.green, green);
+        check_eq(color, "hsluv_to_rgb blue", values.rgb.blue, blue);
+
+        let (red, green, blue) = hpluv_to_rgb(
+            values.hpluv.hue,
+            values.hpluv.saturation,
+            values.hpluv.lightness,
+        );
+        check_eq(color, "hpluv_to_rgb red", values.rgb.red, red);
+        check_eq(color, "hpluv_to_rgb green", values.rgb.green, green);
+        check_eq(color, "hpluv_to_rgb blue", values.rgb.blue, blue);
+
+        let (hue, saturation, lightness) =
+            rgb_to_hsluv(values.rgb.red, values.rgb.green, values.rgb.blue);
+        check_eq(color, "rgb_to_hsluv hue", values.hsluv.hue, hue);
+        check_eq(
+            color,
+            "rgb_to_hsluv saturation",
+            values.hsluv.saturation,
+            saturation,
+        );
+        check_eq(
+            color,
+            "rgb_to_hsluv lightness",
+            values.hsluv.lightness,
+            lightness,
+        );
+
+        let (hue, saturation, lightness) =
+            rgb_to_hpluv(values.rgb.red, values.rgb.green, values.rgb.blue);
+        check_eq(color, "rgb_to_hpluv hue", values.hpluv.hue, hue);
+        check_eq(
+            color,
+            "rgb_to_hpluv saturation",
+            values.hpluv.saturation,
+            saturation,
+        );
+        check_eq(
+            color,
+            "rgb_to_hpluv lightness",
+            values.hpluv.lightness,
+            lightness,
+        );
+    }
+}
+
+fn check_hex(snapshot: &[(&str, ColorValues)]) {
+    for (color, values) in snapshot.iter() {
+        let rgb = Rgb::from_hex(color).unwrap();
+        check_eq(color, "Rgb.red", values.rgb.red, rgb.red);
+        check_eq(color, "Rgb.green", values.rgb.green, rgb.green);
+        check_eq(color, "Rgb.blue", values.rgb.blue, rgb.blue);
+
+        let (hue, saturation, lightness) = hex_to_hsluv(color).unwrap();
+        check_eq(color, "Hsluv.hue", values.hsluv.hue, hue);
+        check_eq(
+            color,
+            "Hsluv.saturation",
+            values.hsluv.saturation,
+            saturation,
+        );
+        check_eq(color, "Hsluv.lightness", values.hsluv.lightness, lightness);
+
+        let (hue, saturation, lightness) = hex_to_hpluv(color).unwrap();
+        check_eq(color, "Hpluv.hue", values.hpluv.hue, hue);
+        check_eq(
+            color,
+            "Hpluv.saturation",
+            values.hpluv.saturation,
+            saturation,
+        );
+        check_eq(color, "Hpluv.lightness", values.hpluv.lightness, lightness);
+
+        #[cfg(feature = "alloc")]
+        {
+            assert_eq!(values.rgb.to_hex(), *color);
+            assert_eq!(
+                hsluv_to_hex(
+                    values.hsluv.hue,
+                    values.hsluv.saturation,
+                    values.hsluv.lightness
+                ),
+                *color
+            );
+            assert_eq!(
+                hpluv_to_hex(
+                    values.hpluv.hue,
+                    values.hpluv.saturation,
+                    values.hpluv.lightness
+                ),
+                *color
+            );
+        }
+    }
+}
+
+#[cfg(feature = "palette")]
+#[test]
+fn test_palette() {
+    use ::palette::{white_point::D65, IntoColor, Mix};
+
+    // palette's D65 white point is rounded differently from HSLuv's, which
+    // shows in the fourth decimal place of XYZ and the second of Luv.
+    fn check_close(color: &str, format: &str, tolerance: f64, expected: f64, actual: f64) {
+        assert!(
+            Float::abs(expected - actual) < tolerance,
+            "{} {}: expected {}, got {}",
+            color,
+            format,
+            expected,
+            actual
+        );
+    }
+
+    for (color, values) in SNAPSHOT.iter() {
+        assert_eq!(Rgb::from(::palette::Srgb::from(values.rgb)), values.rgb);
+        assert_eq!(Xyz::from(::palette::Xyz::from(values.xyz)), values.xyz);
+        assert_eq!(Luv::from(::palette::Luv::from(values.luv)), values.luv);
+        let lch = Lch::from(::palette::Lchuv::from(values.lch));
+        check_eq(color, "Lch.hue", values.lch.hue, lch.hue);
+        let hsluv = Hsluv::from(::palette::Hsluv::from(values.hsluv));
+        check_eq(color, "Hsluv.hue", values.hsluv.hue, hsluv.hue);
+
+        let srgb = ::palette::Srgb::from(values.rgb);
+        let xyz: ::palette::Xyz<D65, f64> = srgb.into_linear().into_color();
+        let xyz = Xyz::from(xyz);
+        check_close(color, "Xyz.x", 1e-3, values.xyz.x, xyz.x);
+        check_close(color, "Xyz.y", 1e-3, values.xyz.y, xyz.y);
+        check_close(color, "Xyz.z", 1e-3, values.xyz.z, xyz.z);
+        let luv: ::palette::Luv<D65, f64> = srgb.into_linear().into_color();
+        let luv = Luv::from(luv);
+        check_close(
+            color,
+            "Luv.lightness",
+            0.05,
+            values.luv.lightness,
+            luv.lightness,
+        );
+        check_close(color, "Luv.u", 0.05, values.luv.u, luv.u);
+        check_close(color, "Luv.v", 0.05, values.luv.v, luv.v);
+        let hsluv: ::palette::Hsluv<D65, f64> = srgb.into_linear().into_color();
+        let hsluv = Hsluv::from(hsluv);
+        check_close(
+            color,
+            "Hsluv.lightness",
+            0.05,
+            values.hsluv.lightness,
+            hsluv.lightness,
+        );
+        if values.hsluv.saturation > 1.0 {
+            check_close(color, "Hsluv.hue", 0.05, values.hsluv.hue, hsluv.hue);
+        }
+    }
+
+    let a = Hsluv::new(350.0, 20.0, 30.0).unwrap();
+    let b = Hsluv::new(30.0, 80.0, 70.0).unwrap();
+    let mixed = a.mix(b, 0.25);
+    let expected = Hsluv::from(::palette::Hsluv::from(a).mix(::palette::Hsluv::from(b), 0.25));
+    check_eq("mix", "Hsluv.hue", expected.hue, mixed.hue);
+    check_eq("mix", "Hsluv.hue", 0.0, mixed.hue);
+    check_eq(
+        "mix",
+        "Hsluv.saturation",
+        expected.saturation,
+        mixed.saturation,
+    );
+    check_eq(
+        "mix",
+        "Hsluv.lightness",
+        expected.lightness,
+        mixed.lightness,
+    );
+}
+
+#[test]
+fn test_layout() {
+    use core::mem::{align_of, size_of};
+
+    assert_eq!(size_of::<Rgb>(), 3 * size_of::<f64>());
+    assert_eq!(size_of::<Xyz>(), 3 * size_of::<f64>());
+    assert_eq!(size_of::<Luv>(), 3 * size_of::<f64>());
+    assert_eq!(size_of::<Lch>(), 3 * size_of::<f64>());
+    assert_eq!(size_of::<Hsluv>(), 3 * size_of::<f64>());
+    assert_eq!(size_of::<Hpluv>(), 3 * size_of::<f64>());
+    assert_eq!(align_of::<Rgb>(), align_of::<f64>());
+    assert_eq!(align_of::<Hsluv>(), align_of::<f64>());
+    assert_eq!(size_of::<[Rgb; 4]>(), 12 * size_of::<f64>());
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn test_slice_casts() {
+    let mut colors = [
+        Rgb {
+            red: 0.1,
+            green: 0.2,
+            blue: 0.3,
+        },
+        Rgb {
+            red: 0.4,
+            green: 0.5,
+            blue: 0.6,
+        },
+    ];
+    assert_eq!(Rgb::as_slice(&colors), &[0.1, 0.2, 0.3, 0.4, 0.5, 0.6]);
+    Rgb::as_mut_slice(&mut colors)[4] = 0.0;
+    assert_eq!(colors[1].green, 0.0);
+
+    let mut components = [10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0];
+    assert_eq!(Hsluv::from_slice(&components[..5]), None);
+    assert_eq!(
+        Hsluv::from_slice(&components[..6]),
+        Some(
+            &[
+                Hsluv {
+                    hue: 10.0,
+                    saturation: 20.0,
+                    lightness: 30.0,
+                },
+                Hsluv {
+                    hue: 40.0,
+                    saturation: 50.0,
+                    lightness: 60.0,
+                },
+            ][..]
+        )
+    );
+    let lch = Lch::from_mut_slice(&mut components[1..4]).unwrap();
+    lch[0].hue = 0.0;
+    assert_eq!(components[3], 0.0);
+}
+
+#[test]
+fn test_approx_eq() {
+    let hsluv = |hue, saturation, lightness| Hsluv {
+        hue,
+        saturation,
+        lightness,
+    };
+    let epsilon = 0.000001;
+    assert!(hsluv(0.0, 50.0, 50.0).approx_eq(&hsluv(360.0, 50.0, 50.0), epsilon));
+    assert!(hsluv(359.9999999, 50.0, 50.0).approx_eq(&hsluv(0.0000001, 50.0, 50.0), epsilon));
+    assert!(hsluv(-90.0, 50.0, 50.0).approx_eq(&hsluv(270.0, 50.0, 50.0), epsilon));
+    assert!(hsluv(10.0, 0.0, 50.0).approx_eq(&hsluv(200.0, 0.0000001, 50.0), epsilon));
+    assert!(hsluv(10.0, 80.0, 0.0).approx_eq(&hsluv(200.0, 20.0, 0.0), epsilon));
+    assert!(hsluv(10.0, 80.0, 100.0).approx_eq(&hsluv(200.0, 0.0, 99.9999999), epsilon));
+    assert!(!hsluv(10.0, 80.0, 50.0).approx_eq(&hsluv(10.001, 80.0, 50.0), epsilon));
+    assert!(!hsluv(10.0, 80.0, 50.0).approx_eq(&hsluv(10.0, 80.001, 50.0), epsilon));
+    assert!(!hsluv(10.0, 80.0, 50.0).approx_eq(&hsluv(10.0, 80.0, 50.001), epsilon));
+    assert!(!hsluv(10.0, 0.0, 50.0).approx_eq(&hsluv(200.0, 1.0, 50.0), epsilon));
+    assert!(!hsluv(f64::NAN, 50.0, 50.0).approx_eq(&hsluv(f64::NAN, 50.0, 50.0), epsilon));
+
+    let gray = Lch {
+        lightness: 50.0,
+        chroma: 0.0,
+        hue: 0.0,
+    };
+    assert!(gray.approx_eq(&Lch { hue: 120.0, ..gray }, epsilon));
+    let red = Lch {
+        chroma: 20.0,
+        ..gray
+    };
+    assert!(red.approx_eq(&Lch { hue: 360.0, ..red }, epsilon));
+    assert!(!red.approx_eq(&Lch { hue: 120.0, ..red }, epsilon));
+
+    for (color, values) in SNAPSHOT.iter() {
+        let rgb = Rgb::from(Hsluv::from(values.rgb));
+        assert!(rgb.approx_eq(&values.rgb, ROUND_TRIP_ERROR), "{}", color);
+        let hsluv = Hsluv::from(Lch::from(values.hsluv));
+        assert!(hsluv.approx_eq(&values.hsluv, MAX_DIFF), "{}", color);
+        let hpluv = Hpluv::from(Lch::from(values.hpluv));
+        assert!(hpluv.approx_eq(&values.hpluv, MAX_DIFF), "{}", color);
+        let xyz = Xyz::from(Luv::from(values.xyz));
+        assert!(xyz.approx_eq(&values.xyz, MAX_DIFF), "{}", color);
+    }
+}
+
+#[test]
+fn test_hsluv_key() {
+    let hsluv = |hue, saturation, lightness| Hsluv {
+        hue,
+        saturation,
+        lightness,
+    };
+    assert_eq!(hsluv(0.0, 50.0, 50.0).key(), hsluv(360.0, 50.0, 50.0).key());
+    assert_eq!(
+        hsluv(-30.0, 50.0, 50.0).key(),
+        hsluv(330.0, 50.0, 50.0).key()
+    );
+    assert_eq!(hsluv(359.999999, 50.0, 50.0).key().hue, 0);
+    assert_eq!(hsluv(10.0, 0.0, 50.0).key(), hsluv(200.0, 0.0, 50.0).key());
+    assert_eq!(hsluv(10.0, 80.0, 0.0).key(), hsluv(0.0, 0.0, 0.0).key());
+    assert_eq!(
+        hsluv(10.0, 80.0, 100.0000001).key(),
+        HsluvKey {
+            hue: 0,
+            saturation: 0,
+            lightness: u16::MAX
+        }
+    );
+    assert_ne!(
+        hsluv(10.0, 80.0, 50.0).key(),
+        hsluv(10.01, 80.0, 50.0).key()
+    );
+    assert!(hsluv(10.0, 80.0, 50.0).key() < hsluv(10.0, 80.0, 60.0).key());
+
+    // Every color of the snapshot keeps its own key, and comes back within
+    // half a step.
+    let mut keys = [HsluvKey {
+        hue: 0,
+        saturation: 0,
+        lightness: 0,
+    }; 4096];
+    assert_eq!(keys.len(), SNAPSHOT.len());
+    for (key, (color, values)) in keys.iter_mut().zip(SNAPSHOT.iter()) {
+        *key = values.hsluv.key();
+        assert_eq!(Hsluv::from(*key).key(), *key, "{}", color);
+        assert!(
+            Hsluv::from(*key).approx_eq(&values.hsluv, 0.0028),
+            "{}",
+            color
+        );
+    }
+    keys.sort_unstable();
+    assert!(keys.windows(2).all(|pair| pair[0] != pair[1]));
+
+    #[cfg(feature = "std")]
+    {
+        let mut palette = std::collections::HashMap::new();
+        palette.insert(hsluv(0.0, 50.0, 50.0).key(), "first");
+        palette.insert(hsluv(360.0, 50.0, 50.0).key(), "second");
+        assert_eq!(palette.len(), 1);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_display_from_str() {
+    use alloc::format;
+    use alloc::string::ToString;
+
+    fn check_round_trip<T>(color: &str, value: T)
+    where
+        T: core::fmt::Display + core::str::FromStr + PartialEq + core::fmt::Debug,
+        T::Err: core::fmt::Debug,
+    {
+        let text = value.to_string();
+        assert_eq!(text.parse::<T>().unwrap(), value, "{}: {}", color, text);
+    }
+
+    for (color, values) in SNAPSHOT.iter() {
+        check_round_trip(color, values.rgb);
+        check_round_trip(color, values.xyz);
+        check_round_trip(color, values.luv);
+        check_round_trip(color, values.lch);
+        check_round_trip(color, values.hsluv);
+        check_round_trip(color, values.hpluv);
+        assert_eq!(
+            color.parse::<Rgb>(),
+            Rgb::from_hex(color).map_err(ParseColorError::Hex)
+        );
+    }
+
+    let hsluv = Hsluv {
+        hue: 250.0,
+        saturation: 80.5,
+        lightness: 40.0,
+    };
+    assert_eq!(hsluv.to_string(), "hsluv(250, 80.5, 40)");
+    assert_eq!(format!("{:.2}", hsluv), "hsluv(250.00, 80.50, 40.00)");
+    let rgb = Rgb::from_rgb8([17, 238, 0]);
+    assert_eq!(format!("{:.3}", rgb), "rgb(0.067, 0.933, 0.000)");
+    assert_eq!(
+        Hpluv {
+            hue: -0.0,
+            saturation: f64::INFINITY,
+            lightness: f64::NAN,
+        }
+        .to_string(),
+        "hpluv(-0, inf, NaN)"
+    );
+
+    assert_eq!("  HSLuv ( 250,80.5 ,  40 ) ".parse(), Ok(hsluv));
+    assert_eq!("hsluv(2.5e2, 8.05e1, 40)".parse(), Ok(hsluv));
+    assert_eq!(" #11EE00".parse(), Ok(rgb));
+    assert_eq!(
+        "rgb(0.5, 1, 0)".parse(),
+        Ok(Rgb::new(0.5, 1.0, 0.0).unwrap())
+    );
+    assert_eq!(
+        "lch(50, 20, 400)".parse(),
+        Ok(Lch {
+            lightness: 50.0,
+            chroma: 20.0,
+            hue: 400.0,
+        })
+    );
+
+    for (text, error) in [
+        (
+            "hpluv(250, 80.5, 40)",
+            ParseColorError::Syntax("hsluv(hue, saturation, lightness)"),
+        ),
+        (
+            "hsluv 250, 80.5, 40",
+            ParseColorError::Syntax("hsluv(hue, saturation, lightness)"),
+        ),
+        (
+            "hsluv(250, 80.5, 40",
+            ParseColorError::Syntax("hsluv(hue, saturation, lightness)"),
+        ),
+        ("hsluv()", ParseColorError::ComponentCount(0)),
+        ("hsluv(250, 80.5)", ParseColorError::ComponentCount(2)),
+        (
+            "hsluv(250, 80.5, 40, 1)",
+            ParseColorError::ComponentCount(4),
+        ),
+        ("hsluv(250, , 40)", ParseColorError::Component(1)),
+        ("hsluv(250, 80.5, forty)", ParseColorError::Component(2)),
+    ] {
+        assert_eq!(text.parse::<Hsluv>(), Err(error), "{}", text);
+    }
+    assert_eq!(
+        "#11ee0".parse::<Rgb>(),
+        Err(ParseColorError::Hex(HexError::Length))
+    );
+    assert_eq!(
+        "rgba(0, 0, 0, 1)".parse::<Rgb>(),
+        Err(ParseColorError::Syntax("rgb(red, green, blue) or #rrggbb"))
+    );
+    assert_eq!(
+        "hsluv(250, 80.5)".parse::<Hsluv>().unwrap_err().to_string(),
+        "expected 3 components, found 2"
+    );
+    assert_eq!(
+        "xyz".parse::<Xyz>().unwrap_err().to_string(),
+        "expected `xyz(x, y, z)`"
+    );
+    assert_eq!(
+        "luv(1, 2, three)".parse::<Luv>().unwrap_err().to_string(),
+        "component 2 isn't a number"
+    );
+    assert_eq!(
+        "#11eg00".parse::<Rgb>().unwrap_err().to_string(),
+        "invalid hex digit"
+    );
+}
@@ -0,0 +1,155 @@
+//! Text forms of the color types, such as `hsluv(250, 80.5, 40)`.
+//!
+//! `Display` writes each component with the shortest digits that read back
+//! to the same `f64`, so `FromStr` returns exactly the color that was
+//! written. A precision, as in `{:.2}`, applies to every component. `FromStr`
+//! takes the space's name in any case and surrounding whitespace, and for
+//! `Rgb` also `#rrggbb`. It doesn't check ranges; follow it with `normalize`
+//! or `new` where that matters.
+
+use crate::{HexError, Hpluv, Hsluv, Lch, Luv, Rgb, Xyz};
+use core::fmt;
+use core::str::FromStr;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ParseColorError {
+    /// Not in the form of the type being parsed, which is held here, such
+    /// as `"hsluv(hue, saturation, lightness)"`.
+    Syntax(&'static str),
+    /// Holds how many components there were instead of three.
+    ComponentCount(usize),
+    /// Holds the index of the first component that isn't a number.
+    Component(usize),
+    /// Started with `#` but isn't `#rrggbb`.
+    Hex(HexError),
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseColorError::Syntax(form) => write!(f, "expected `{}`", form),
+            ParseColorError::ComponentCount(count) => {
+                write!(f, "expected 3 components, found {}", count)
+            }
+            ParseColorError::Component(index) => write!(f, "component {} isn't a number", index),
+            ParseColorError::Hex(HexError::Prefix) => {
+                f.write_str("hex color doesn't start with `#`")
+            }
+            ParseColorError::Hex(HexError::Length) => f.write_str("expected 6 hex digits"),
+            ParseColorError::Hex(HexError::Digit) => f.write_str("invalid hex digit"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseColorError {}
+
+fn parse_components(
+    s: &str,
+    name: &str,
+    form: &'static str,
+) -> Result<(f64, f64, f64), ParseColorError> {
+    let s = s.trim();
+    let components = match s.get(..name.len()) {
+        Some(prefix) if prefix.eq_ignore_ascii_case(name) => s[name.len()..]
+            .trim_start()
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+            .ok_or(ParseColorError::Syntax(form))?,
+        _ => return Err(ParseColorError::Syntax(form)),
+    };
+    let count = match components.trim() {
+        "" => 0,
+        components => components.split(',').count(),
+    };
+    if count != 3 {
+        return Err(ParseColorError::ComponentCount(count));
+    }
+    let mut values = [0.0; 3];
+    for (i, (value, component)) in values.iter_mut().zip(components.split(',')).enumerate() {
+        *value = component
+            .trim()
+            .parse()
+            .map_err(|_| ParseColorError::Component(i))?;
+    }
+    Ok((values[0], values[1], values[2]))
+}
+
+fn write_components(f: &mut fmt::Formatter, name: &str, (a, b, c): (f64, f64, f64)) -> fmt::Result {
+    match f.precision() {
+        Some(precision) => write!(
+            f,
+            "{}({:.*}, {:.*}, {:.*})",
+            name, precision, a, precision, b, precision, c
+        ),
+        None => write!(f, "{}({}, {}, {})", name, a, b, c),
+    }
+}
+
+macro_rules! impl_text {
+    ($t:ident, $name:literal, $form:literal, $components:ident, $a:ident, $b:ident, $c:ident) => {
+        impl fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write_components(f, $name, self.$components())
+            }
+        }
+
+        impl FromStr for $t {
+            type Err = ParseColorError;
+
+            fn from_str(s: &str) -> Result<$t, ParseColorError> {
+                let ($a, $b, $c) = parse_components(s, $name, $form)?;
+                Ok($t { $a, $b, $c })
+            }
+        }
+    };
+}
+
+impl_text!(Xyz, "xyz", "xyz(x, y, z)", xyz, x, y, z);
+impl_text!(Luv, "luv", "luv(lightness, u, v)", luv, lightness, u, v);
+impl_text!(
+    Lch,
+    "lch",
+    "lch(lightness, chroma, hue)",
+    lch,
+    lightness,
+    chroma,
+    hue
+);
+impl_text!(
+    Hsluv,
+    "hsluv",
+    "hsluv(hue, saturation, lightness)",
+    hsl,
+    hue,
+    saturation,
+    lightness
+);
+impl_text!(
+    Hpluv,
+    "hpluv",
+    "hpluv(hue, saturation, lightness)",
+    hsl,
+    hue,
+    saturation,
+    lightness
+);
+
+impl fmt::Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_components(f, "rgb", self.rgb())
+    }
+}
+
+impl FromStr for Rgb {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Rgb, ParseColorError> {
+        let s = s.trim();
+        if s.starts_with('#') {
+            return Rgb::from_hex(s).map_err(ParseColorError::Hex);
+        }
+        let (red, green, blue) = parse_components(s, "rgb", "rgb(red, green, blue) or #rrggbb")?;
+        Ok(Rgb { red, green, blue })
+    }
+}
@@ -0,0 +1,280 @@
+//! JavaScript bindings through wasm-bindgen, enabled by the `wasm` feature.
+//!
+//! The API mirrors version 1 of the official `hsluv` npm package: a mutable
+//! `Hsluv` converter object whose fields hold a color in every space, and
+//! whose methods convert between them in place.
+//!
+//! ```js
+//! const conv = new Hsluv();
+//! conv.hex = "#11ee00";
+//! conv.hexToHsluv();
+//! console.log(conv.hsluv_h, conv.hsluv_s, conv.hsluv_l); // 127.47… 100… 82.52…
+//! ```
+//!
+//! Unlike the npm package, `hexToRgb` and the methods built on it throw on
+//! anything but `#rrggbb`. The gamut queries are free functions.
+
+use crate::{Hpluv, Hsluv, Lch, Luv, Rgb, Xyz};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use wasm_bindgen::prelude::*;
+
+/// `hpluv_p` is HPLuv saturation, named as in the npm package.
+#[wasm_bindgen(js_name = Hsluv, getter_with_clone)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Converter {
+    pub hex: String,
+    pub rgb_r: f64,
+    pub rgb_g: f64,
+    pub rgb_b: f64,
+    pub xyz_x: f64,
+    pub xyz_y: f64,
+    pub xyz_z: f64,
+    pub luv_l: f64,
+    pub luv_u: f64,
+    pub luv_v: f64,
+    pub lch_l: f64,
+    pub lch_c: f64,
+    pub lch_h: f64,
+    pub hsluv_h: f64,
+    pub hsluv_s: f64,
+    pub hsluv_l: f64,
+    pub hpluv_h: f64,
+    pub hpluv_p: f64,
+    pub hpluv_l: f64,
+}
+
+impl Default for Converter {
+    fn default() -> Converter {
+        Converter {
+            hex: String::from("#000000"),
+            rgb_r: 0.0,
+            rgb_g: 0.0,
+            rgb_b: 0.0,
+            xyz_x: 0.0,
+            xyz_y: 0.0,
+            xyz_z: 0.0,
+            luv_l: 0.0,
+            luv_u: 0.0,
+            luv_v: 0.0,
+            lch_l: 0.0,
+            lch_c: 0.0,
+            lch_h: 0.0,
+            hsluv_h: 0.0,
+            hsluv_s: 0.0,
+            hsluv_l: 0.0,
+            hpluv_h: 0.0,
+            hpluv_p: 0.0,
+            hpluv_l: 0.0,
+        }
+    }
+}
+
+#[wasm_bindgen(js_class = Hsluv)]
+impl Converter {
+    /// Starts out black, with every field filled in.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Converter {
+        Converter::default()
+    }
+
+    fn rgb(&self) -> Rgb {
+        Rgb {
+            red: self.rgb_r,
+            green: self.rgb_g,
+            blue: self.rgb_b,
+        }
+    }
+
+    fn set_rgb(&mut self, rgb: Rgb) {
+        (self.rgb_r, self.rgb_g, self.rgb_b) = rgb.rgb();
+    }
+
+    fn xyz(&self) -> Xyz {
+        Xyz {
+            x: self.xyz_x,
+            y: self.xyz_y,
+            z: self.xyz_z,
+        }
+    }
+
+    fn luv(&self) -> Luv {
+        Luv {
+            lightness: self.luv_l,
+            u: self.luv_u,
+            v: self.luv_v,
+        }
+    }
+
+    fn lch(&self) -> Lch {
+        Lch {
+            lightness: self.lch_l,
+            chroma: self.lch_c,
+            hue: self.lch_h,
+        }
+    }
+
+    fn set_lch(&mut self, lch: Lch) {
+        (self.lch_l, self.lch_c, self.lch_h) = lch.lch();
+    }
+
+    #[wasm_bindgen(js_name = rgbToHex)]
+    pub fn rgb_to_hex(&mut self) {
+        self.hex = self.rgb().to_hex();
+    }
+
+    /// Throws if `hex` isn't `#rrggbb`.
+    #[wasm_bindgen(js_name = hexToRgb)]
+    pub fn hex_to_rgb(&mut self) -> Result<(), JsError> {
+        let rgb = Rgb::from_hex(&self.hex)
+            .map_err(|err| JsError::new(&format!("invalid hex color {:?}: {:?}", self.hex, err)))?;
+        self.set_rgb(rgb);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = xyzToRgb)]
+    pub fn xyz_to_rgb(&mut self) {
+        self.set_rgb(Rgb::from(self.xyz()));
+    }
+
+    #[wasm_bindgen(js_name = rgbToXyz)]
+    pub fn rgb_to_xyz(&mut self) {
+        (self.xyz_x, self.xyz_y, self.xyz_z) = Xyz::from(self.rgb()).xyz();
+    }
+
+    #[wasm_bindgen(js_name = xyzToLuv)]
+    pub fn xyz_to_luv(&mut self) {
+        (self.luv_l, self.luv_u, self.luv_v) = Luv::from(self.xyz()).luv();
+    }
+
+    #[wasm_bindgen(js_name = luvToXyz)]
+    pub fn luv_to_xyz(&mut self) {
+        (self.xyz_x, self.xyz_y, self.xyz_z) = Xyz::from(self.luv()).xyz();
+    }
+
+    #[wasm_bindgen(js_name = luvToLch)]
+    pub fn luv_to_lch(&mut self) {
+        self.set_lch(Lch::from(self.luv()));
+    }
+
+    #[wasm_bindgen(js_name = lchToLuv)]
+    pub fn lch_to_luv(&mut self) {
+        (self.luv_l, self.luv_u, self.luv_v) = Luv::from(self.lch()).luv();
+    }
+
+    #[wasm_bindgen(js_name = hsluvToLch)]
+    pub fn hsluv_to_lch(&mut self) {
+        self.set_lch(Lch::from(Hsluv {
+            hue: self.hsluv_h,
+            saturation: self.hsluv_s,
+            lightness: self.hsluv_l,
+        }));
+    }
+
+    #[wasm_bindgen(js_name = lchToHsluv)]
+    pub fn lch_to_hsluv(&mut self) {
+        (self.hsluv_h, self.hsluv_s, self.hsluv_l) = Hsluv::from(self.lch()).hsl();
+    }
+
+    #[wasm_bindgen(js_name = hpluvToLch)]
+    pub fn hpluv_to_lch(&mut self) {
+        self.set_lch(Lch::from(Hpluv {
+            hue: self.hpluv_h,
+            saturation: self.hpluv_p,
+            lightness: self.hpluv_l,
+        }));
+    }
+
+    #[wasm_bindgen(js_name = lchToHpluv)]
+    pub fn lch_to_hpluv(&mut self) {
+        (self.hpluv_h, self.hpluv_p, self.hpluv_l) = Hpluv::from(self.lch()).hsl();
+    }
+
+    #[wasm_bindgen(js_name = hsluvToRgb)]
+    pub fn hsluv_to_rgb(&mut self) {
+        self.hsluv_to_lch();
+        self.lch_to_rgb();
+    }
+
+    #[wasm_bindgen(js_name = hpluvToRgb)]
+    pub fn hpluv_to_rgb(&mut self) {
+        self.hpluv_to_lch();
+        self.lch_to_rgb();
+    }
+
+    #[wasm_bindgen(js_name = hsluvToHex)]
+    pub fn hsluv_to_hex(&mut self) {
+        self.hsluv_to_rgb();
+        self.rgb_to_hex();
+    }
+
+    #[wasm_bindgen(js_name = hpluvToHex)]
+    pub fn hpluv_to_hex(&mut self) {
+        self.hpluv_to_rgb();
+        self.rgb_to_hex();
+    }
+
+    #[wasm_bindgen(js_name = rgbToHsluv)]
+    pub fn rgb_to_hsluv(&mut self) {
+        self.rgb_to_lch();
+        self.lch_to_hsluv();
+    }
+
+    #[wasm_bindgen(js_name = rgbToHpluv)]
+    pub fn rgb_to_hpluv(&mut self) {
+        self.rgb_to_lch();
+        self.lch_to_hpluv();
+    }
+
+    /// Throws if `hex` isn't `#rrggbb`.
+    #[wasm_bindgen(js_name = hexToHsluv)]
+    pub fn hex_to_hsluv(&mut self) -> Result<(), JsError> {
+        self.hex_to_rgb()?;
+        self.rgb_to_hsluv();
+        Ok(())
+    }
+
+    /// Throws if `hex` isn't `#rrggbb`.
+    #[wasm_bindgen(js_name = hexToHpluv)]
+    pub fn hex_to_hpluv(&mut self) -> Result<(), JsError> {
+        self.hex_to_rgb()?;
+        self.rgb_to_hpluv();
+        Ok(())
+    }
+
+    fn lch_to_rgb(&mut self) {
+        self.lch_to_luv();
+        self.luv_to_xyz();
+        self.xyz_to_rgb();
+    }
+
+    fn rgb_to_lch(&mut self) {
+        self.rgb_to_xyz();
+        self.xyz_to_luv();
+        self.luv_to_lch();
+    }
+}
+
+#[wasm_bindgen(js_name = maxChromaForLightnessHue)]
+pub fn max_chroma_for_lightness_hue(lightness: f64, hue: f64) -> f64 {
+    crate::max_chroma_for_lightness_hue(lightness, hue)
+}
+
+#[wasm_bindgen(js_name = maxSafeChromaForLightness)]
+pub fn max_safe_chroma_for_lightness(lightness: f64) -> f64 {
+    crate::max_safe_chroma_for_lightness(lightness)
+}
+
+/// The most chromatic color of `hue`, as `[lightness, chroma, hue]`.
+#[wasm_bindgen(js_name = cuspForHue)]
+pub fn cusp_for_hue(hue: f64) -> Vec<f64> {
+    let cusp = crate::cusp_for_hue(hue);
+    alloc::vec![cusp.lightness, cusp.chroma, cusp.hue]
+}
+
+/// `[min, max]`, or `undefined` if `chroma` is beyond the hue's cusp.
+#[wasm_bindgen(js_name = lightnessRangeForChromaHue)]
+pub fn lightness_range_for_chroma_hue(chroma: f64, hue: f64) -> Option<Vec<f64>> {
+    crate::lightness_range_for_chroma_hue(chroma, hue).map(|(min, max)| alloc::vec![min, max])
+}
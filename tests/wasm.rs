@@ -0,0 +1,137 @@
+//! The `wasm` bindings, run under Node with
+//! `cargo test --target wasm32-unknown-unknown --features wasm --test wasm`
+//! and `wasm-bindgen-test-runner` as the target's runner.
+
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+use hsluv::wasm::Converter;
+use hsluv::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const MAX_DIFF: f64 = 0.00000001;
+
+fn check_eq(color: &str, format: &str, expected: f64, actual: f64) {
+    if (expected - actual).abs() >= MAX_DIFF {
+        panic!(
+            "{} {}: expected {}, got {}",
+            color, format, expected, actual
+        )
+    }
+}
+
+// (hex, hsluv, hpluv) from snapshot-rev4.json.
+const SNAPSHOT: [(&str, [f64; 3], [f64; 3]); 3] = [
+    (
+        "#11ee00",
+        [127.47898819200516, 100.00000000000242, 82.52131190083256],
+        [127.47898819200516, 308.19522276267344, 82.52131190083256],
+    ),
+    ("#ffffff", [0.0, 0.0, 100.0], [0.0, 0.0, 100.0]),
+    ("#000000", [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]),
+];
+
+#[wasm_bindgen_test]
+fn test_snapshot() {
+    for &(hex, hsluv, hpluv) in SNAPSHOT.iter() {
+        let mut conv = Converter::new();
+        conv.hex = hex.into();
+        conv.hex_to_hsluv().unwrap();
+        check_eq(hex, "hsluv_h", hsluv[0], conv.hsluv_h);
+        check_eq(hex, "hsluv_s", hsluv[1], conv.hsluv_s);
+        check_eq(hex, "hsluv_l", hsluv[2], conv.hsluv_l);
+        conv.hex_to_hpluv().unwrap();
+        check_eq(hex, "hpluv_h", hpluv[0], conv.hpluv_h);
+        check_eq(hex, "hpluv_p", hpluv[1], conv.hpluv_p);
+        check_eq(hex, "hpluv_l", hpluv[2], conv.hpluv_l);
+
+        let mut conv = Converter::new();
+        (conv.hsluv_h, conv.hsluv_s, conv.hsluv_l) = (hsluv[0], hsluv[1], hsluv[2]);
+        conv.hsluv_to_hex();
+        assert_eq!(conv.hex, hex);
+        (conv.hpluv_h, conv.hpluv_p, conv.hpluv_l) = (hpluv[0], hpluv[1], hpluv[2]);
+        conv.hpluv_to_hex();
+        assert_eq!(conv.hex, hex);
+    }
+}
+
+// The bindings must give exactly what the crate gives natively.
+#[wasm_bindgen_test]
+fn test_matches_crate() {
+    for red in 0..=16 {
+        for green in 0..=16 {
+            for blue in 0..=16 {
+                let rgb = Rgb::from_rgb8([red * 15, green * 15, blue * 15]);
+                let mut conv = Converter::new();
+                (conv.rgb_r, conv.rgb_g, conv.rgb_b) = rgb.rgb();
+                conv.rgb_to_hsluv();
+                conv.rgb_to_hpluv();
+                let hsluv = (conv.hsluv_h, conv.hsluv_s, conv.hsluv_l);
+                let hpluv = (conv.hpluv_h, conv.hpluv_p, conv.hpluv_l);
+                assert_eq!(hsluv, Hsluv::from(rgb).hsl());
+                assert_eq!(hpluv, Hpluv::from(rgb).hsl());
+                assert_eq!(
+                    (conv.lch_l, conv.lch_c, conv.lch_h),
+                    Lch::from(Luv::from(Xyz::from(rgb))).lch()
+                );
+
+                conv.hsluv_to_rgb();
+                assert_eq!(
+                    (conv.rgb_r, conv.rgb_g, conv.rgb_b),
+                    hsluv_to_rgb(hsluv.0, hsluv.1, hsluv.2)
+                );
+                conv.hpluv_to_rgb();
+                assert_eq!(
+                    (conv.rgb_r, conv.rgb_g, conv.rgb_b),
+                    hpluv_to_rgb(hpluv.0, hpluv.1, hpluv.2)
+                );
+                conv.rgb_to_hex();
+                assert_eq!(conv.hex, Rgb::from(Hpluv::from(rgb)).to_hex());
+            }
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_invalid_hex() {
+    for &hex in ["", "11ee00", "#11ee0", "#11ee00 ", "#11eg00"].iter() {
+        let mut conv = Converter::new();
+        conv.hex = hex.into();
+        assert!(conv.hex_to_rgb().is_err(), "{:?}", hex);
+        assert!(conv.hex_to_hsluv().is_err(), "{:?}", hex);
+        assert!(conv.hex_to_hpluv().is_err(), "{:?}", hex);
+        assert_eq!(
+            conv,
+            Converter {
+                hex: hex.into(),
+                ..Converter::new()
+            }
+        );
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_gamut() {
+    for hue in (0..360).step_by(15).map(f64::from) {
+        let cusp = hsluv::cusp_for_hue(hue);
+        assert_eq!(
+            hsluv::wasm::cusp_for_hue(hue),
+            [cusp.lightness, cusp.chroma, cusp.hue]
+        );
+        for lightness in (0..=100).step_by(5).map(f64::from) {
+            assert_eq!(
+                hsluv::wasm::max_chroma_for_lightness_hue(lightness, hue),
+                hsluv::max_chroma_for_lightness_hue(lightness, hue)
+            );
+        }
+        let range = hsluv::wasm::lightness_range_for_chroma_hue(cusp.chroma / 2.0, hue).unwrap();
+        let (min, max) = hsluv::lightness_range_for_chroma_hue(cusp.chroma / 2.0, hue).unwrap();
+        assert_eq!(range, [min, max]);
+        assert!(hsluv::wasm::lightness_range_for_chroma_hue(cusp.chroma + 1.0, hue).is_none());
+    }
+    for lightness in (0..=100).step_by(5).map(f64::from) {
+        assert_eq!(
+            hsluv::wasm::max_safe_chroma_for_lightness(lightness),
+            hsluv::max_safe_chroma_for_lightness(lightness)
+        );
+    }
+}